@@ -0,0 +1,123 @@
+//! Procedural macros expanding to the fnv1a hash of a str literal as a numeric literal,
+//! so no hashing happens at runtime even in unoptimized builds.
+
+extern crate proc_macro;
+
+use proc_macro::{TokenStream, TokenTree};
+
+/// Expands to the 32-bits fnv1a hash of a str literal as a `u32` literal.
+///
+/// ```
+/// use const_fnv1a_hash_macros::fnv1a_32;
+///
+/// assert_eq!(fnv1a_32!("hello"), 1335831723u32);
+/// ```
+///
+/// Only str literals are accepted:
+///
+/// ```compile_fail
+/// use const_fnv1a_hash_macros::fnv1a_32;
+///
+/// let input = "hello";
+/// let _ = fnv1a_32!(input);
+/// ```
+#[proc_macro]
+pub fn fnv1a_32(input: TokenStream) -> TokenStream {
+    match parse_str_literal(input) {
+        Ok(value) => format!("{}u32", const_fnv1a_hash::fnv1a_hash_str_32(&value)),
+        Err(message) => format!("compile_error!({:?})", message),
+    }
+    .parse()
+    .unwrap()
+}
+
+/// Expands to the 64-bits fnv1a hash of a str literal as a `u64` literal.
+///
+/// ```
+/// use const_fnv1a_hash_macros::fnv1a_64;
+///
+/// assert_eq!(fnv1a_64!("hello"), 0xa430d84680aabd0b);
+/// ```
+///
+/// Only str literals are accepted:
+///
+/// ```compile_fail
+/// use const_fnv1a_hash_macros::fnv1a_64;
+///
+/// let _ = fnv1a_64!(42);
+/// ```
+#[proc_macro]
+pub fn fnv1a_64(input: TokenStream) -> TokenStream {
+    match parse_str_literal(input) {
+        Ok(value) => format!("{}u64", const_fnv1a_hash::fnv1a_hash_str_64(&value)),
+        Err(message) => format!("compile_error!({:?})", message),
+    }
+    .parse()
+    .unwrap()
+}
+
+/// Extracts the value of the single str literal making up input.
+fn parse_str_literal(input: TokenStream) -> Result<String, &'static str> {
+    const EXPECTED: &str = "expected a single str literal";
+
+    let mut tokens = input.into_iter();
+    let literal = match (tokens.next(), tokens.next()) {
+        (Some(TokenTree::Literal(literal)), None) => literal.to_string(),
+        (Some(TokenTree::Group(group)), None) => return parse_str_literal(group.stream()),
+        _ => return Err(EXPECTED),
+    };
+
+    if let Some(raw) = literal.strip_prefix('r') {
+        let hashes = raw.len() - raw.trim_start_matches('#').len();
+        return raw
+            .get(hashes + 1..raw.len() - hashes - 1)
+            .map(String::from)
+            .ok_or(EXPECTED);
+    }
+
+    match literal.strip_prefix('"').and_then(|s| s.strip_suffix('"')) {
+        Some(body) => unescape(body).ok_or(EXPECTED),
+        None => Err(EXPECTED),
+    }
+}
+
+/// Resolves the escape sequences of a non-raw str literal body.
+fn unescape(body: &str) -> Option<String> {
+    let mut value = String::with_capacity(body.len());
+    let mut chars = body.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            value.push(c);
+            continue;
+        }
+        match chars.next()? {
+            'n' => value.push('\n'),
+            'r' => value.push('\r'),
+            't' => value.push('\t'),
+            '0' => value.push('\0'),
+            '\\' => value.push('\\'),
+            '\'' => value.push('\''),
+            '"' => value.push('"'),
+            'x' => {
+                let digits: String = chars.by_ref().take(2).collect();
+                value.push(u8::from_str_radix(&digits, 16).ok()? as char);
+            }
+            'u' => {
+                if chars.next()? != '{' {
+                    return None;
+                }
+                let digits: String = chars.by_ref().take_while(|&c| c != '}').collect();
+                let code = u32::from_str_radix(&digits.replace('_', ""), 16).ok()?;
+                value.push(core::char::from_u32(code)?);
+            }
+            '\n' => {
+                while chars.peek().is_some_and(|c| c.is_whitespace()) {
+                    chars.next();
+                }
+            }
+            _ => return None,
+        }
+    }
+    Some(value)
+}
@@ -0,0 +1,21 @@
+use const_fnv1a_hash::{fnv1a_hash_str_32, fnv1a_hash_str_64};
+use const_fnv1a_hash_macros::{fnv1a_32, fnv1a_64};
+
+#[test]
+fn expands_to_function_output() {
+    assert_eq!(fnv1a_32!("hello"), fnv1a_hash_str_32("hello"));
+    assert_eq!(fnv1a_64!("hello"), fnv1a_hash_str_64("hello"));
+    assert_eq!(fnv1a_32!(""), fnv1a_hash_str_32(""));
+    assert_eq!(fnv1a_32!("caf\u{e9}\t\"\x41\\"), fnv1a_hash_str_32("caf\u{e9}\t\"\x41\\"));
+    assert_eq!(fnv1a_64!(r#"raw "quoted" \n"#), fnv1a_hash_str_64(r#"raw "quoted" \n"#));
+}
+
+#[test]
+fn usable_as_match_pattern() {
+    let matched = match fnv1a_hash_str_32("post") {
+        fnv1a_32!("get") => "get",
+        fnv1a_32!("post") => "post",
+        _ => "other",
+    };
+    assert_eq!(matched, "post");
+}
@@ -0,0 +1,91 @@
+//! Throughput comparison of fnv1a against a table driven CRC-32.
+
+use std::format;
+use std::string::String;
+use std::time::Instant;
+use std::vec::Vec;
+
+/// Builds the CRC-32 (IEEE 802.3, reflected 0xEDB88320) lookup table.
+const fn build_crc32_table() -> [u32; 256] {
+    let mut table = [0; 256];
+
+    let mut i = 0;
+    while i < table.len() {
+        let mut crc = i as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 == 1 { (crc >> 1) ^ 0xEDB8_8320 } else { crc >> 1 };
+            bit += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+}
+
+const CRC32_TABLE: [u32; 256] = build_crc32_table();
+
+/// Amount of bytes hashed per measurement, spread over repeated calls.
+const BYTES_PER_MEASUREMENT: usize = 1 << 22;
+
+/// Computes the CRC-32 (IEEE 802.3) checksum of the given slice.
+pub const fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = !0u32;
+
+    let mut i = 0;
+    while i < bytes.len() {
+        crc = CRC32_TABLE[((crc ^ bytes[i] as u32) & 0xFF) as usize] ^ (crc >> 8);
+        i += 1;
+    }
+    !crc
+}
+
+/// Returns the nanoseconds per byte taken by hash over size sized inputs.
+/// Zero sized inputs are measured per call instead.
+fn measure(size: usize, hash: fn(&[u8]) -> u32) -> f64 {
+    let input: Vec<u8> = (0..size).map(|i| i as u8).collect();
+    let iterations = (BYTES_PER_MEASUREMENT / size.max(1)).max(1);
+
+    let start = Instant::now();
+    for _ in 0..iterations {
+        core::hint::black_box(hash(core::hint::black_box(&input)));
+    }
+    let elapsed = start.elapsed().as_nanos().max(1) as f64;
+    elapsed / (iterations * size.max(1)) as f64
+}
+
+/// Measures `(size, fnv_ns_per_byte, crc_ns_per_byte)` of 32-bits fnv1a and CRC-32 for every input size.
+pub fn throughput_comparison(input_sizes: &[usize]) -> Vec<(usize, f64, f64)> {
+    input_sizes
+        .iter()
+        .map(|&size| (size, measure(size, |bytes| crate::fnv1a_hash_32_case_sensitive(bytes, None)), measure(size, crc32)))
+        .collect()
+}
+
+/// Formats the results of `throughput_comparison` as a human-readable table.
+pub fn format_comparison(results: &[(usize, f64, f64)]) -> String {
+    let mut table = String::from("     size  fnv1a ns/B  crc32 ns/B\n");
+    for (size, fnv, crc) in results {
+        table += &format!("{:>9}  {:>10.3}  {:>10.3}\n", size, fnv, crc);
+    }
+    table
+}
+
+#[test]
+fn bench_test_crc32() {
+    assert_eq!(crc32(b""), 0);
+    assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+}
+
+#[test]
+fn bench_test_throughput_comparison() {
+    let results = throughput_comparison(&[0, 16, 1024]);
+    assert_eq!(results.len(), 3);
+    for &(_, fnv, crc) in results.iter() {
+        assert!(fnv > 0.0 && crc > 0.0);
+    }
+
+    let table = format_comparison(&results);
+    assert_eq!(table.lines().count(), 4);
+    assert!(table.contains("fnv1a ns/B"));
+}
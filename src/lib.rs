@@ -1,20 +1,41 @@
 #![no_std]
+// Tests keep exercising the deprecated `fold_to_upper: bool` API.
+#![cfg_attr(test, allow(deprecated))]
 
-const FNV_OFFSET_BASIS_32: u32 = 0x811c9dc5;
-const FNV_OFFSET_BASIS_64: u64 = 0xcbf29ce484222325;
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
-const FNV_PRIME_32: u32 = 0x01000193;
-const FNV_PRIME_64: u64 = 0x00000100000001B3;
+#[cfg(feature = "bench")]
+pub mod bench;
+
+/// 32-bits FNV offset basis, the initial hash state.
+pub const FNV_OFFSET_BASIS_32: u32 = 0x811c9dc5;
+/// 64-bits FNV offset basis, the initial hash state.
+pub const FNV_OFFSET_BASIS_64: u64 = 0xcbf29ce484222325;
+/// 128-bits FNV offset basis, the initial hash state.
+pub const FNV_OFFSET_BASIS_128: u128 = 0x6c62272e07bb0142_62b821756295c58d;
+
+/// 32-bits FNV prime.
+pub const FNV_PRIME_32: u32 = 0x01000193;
+/// 64-bits FNV prime.
+pub const FNV_PRIME_64: u64 = 0x00000100000001B3;
+/// 128-bits FNV prime.
+pub const FNV_PRIME_128: u128 = 0x0000000001000000_000000000000013B;
+
+/// Modular inverse of `FNV_PRIME_32` modulo 2^32.
+pub const FNV_PRIME_32_INVERSE: u32 = 0x359c449b;
+/// Modular inverse of `FNV_PRIME_64` modulo 2^64.
+pub const FNV_PRIME_64_INVERSE: u64 = 0xce965057aff6957b;
 
 const ASCII_CASE_MASK: u8 = 0b0010_0000;
 
-/// Computes 64-bits fnv1a hash of the given slice, or up-to limit if provided.
+/// Returns the amount of bytes to hash, up-to limit if provided.
 /// If limit is zero or exceeds slice length, slice length is used instead.
-pub const fn fnv1a_hash_64(bytes: &[u8], limit: Option<usize>, case: bool) -> u64 {
-    let mut hash = FNV_OFFSET_BASIS_64;
-
-    let mut i = 0;
-    let len = match limit {
+#[inline(always)]
+const fn effective_len(bytes: &[u8], limit: Option<usize>) -> usize {
+    match limit {
         Some(v) => {
             if v <= bytes.len() && v > 0 {
                 v
@@ -23,48 +44,248 @@ pub const fn fnv1a_hash_64(bytes: &[u8], limit: Option<usize>, case: bool) -> u6
             }
         }
         None => bytes.len(),
-    };
+    }
+}
+
+/// Clears the ascii case bit of the byte if fold_to_upper is set and the bit is set.
+/// This hashes ascii lowercase letters as uppercase, but also changes any other byte with
+/// the bit set, like digits, space and UTF-8 bytes. Kept for compatibility with hashes
+/// computed by earlier versions; see `fold_ascii_letter` for letters-only folding.
+#[inline(always)]
+const fn fold_case(byte: u8, fold_to_upper: bool) -> u8 {
+    if fold_to_upper && (byte & ASCII_CASE_MASK == ASCII_CASE_MASK) {
+        byte ^ ASCII_CASE_MASK
+    }
+    else {
+        byte
+    }
+}
+
+/// Folds ascii lowercase letters to uppercase if fold_to_upper is set.
+/// All other bytes are returned unchanged.
+#[inline(always)]
+const fn fold_ascii_letter(byte: u8, fold_to_upper: bool) -> u8 {
+    if fold_to_upper {
+        byte.to_ascii_uppercase()
+    }
+    else {
+        byte
+    }
+}
+
+/// Computes 128-bits fnv1a hash of the given slice, or up-to limit if provided.
+/// If limit is zero or exceeds slice length, slice length is used instead.
+/// If fold_to_upper is set, ascii lowercase letters are hashed as uppercase and all other bytes unchanged.
+pub const fn fnv1a_hash_128(bytes: &[u8], limit: Option<usize>, fold_to_upper: bool) -> u128 {
+    let mut hash = FNV_OFFSET_BASIS_128;
+
+    let mut i = 0;
+    let len = effective_len(bytes, limit);
 
     while i < len {
-        let value = if case && (bytes[i] & ASCII_CASE_MASK == ASCII_CASE_MASK) {
-            bytes[i] ^ ASCII_CASE_MASK
-        }
-        else {
-            bytes[i]
-        };
-        hash ^= value as u64;
+        hash ^= fold_ascii_letter(bytes[i], fold_to_upper) as u128;
+        hash = hash.wrapping_mul(FNV_PRIME_128);
+        i += 1;
+    }
+    hash
+}
+
+/// XORs higher and lower 16-bits of a 32-bits hash.
+#[inline(always)]
+const fn xor_fold_16(hash: u32) -> u16 {
+    let bytes = hash.to_ne_bytes();
+    let upper: u16 = u16::from_ne_bytes([bytes[0], bytes[1]]);
+    let lower: u16 = u16::from_ne_bytes([bytes[2], bytes[3]]);
+    upper ^ lower
+}
+
+/// Continues 64-bits fnv1a hash from the given state over the first len bytes.
+#[inline(always)]
+const fn fnv1a_continue_64(hash: u64, bytes: &[u8], len: usize, fold_to_upper: bool) -> u64 {
+    fnv1a_continue_range_64(hash, bytes, 0, len, fold_to_upper)
+}
+
+/// Continues 64-bits fnv1a hash from the given state over the bytes from start up-to end.
+#[inline(always)]
+const fn fnv1a_continue_range_64(mut hash: u64, bytes: &[u8], start: usize, end: usize, fold_to_upper: bool) -> u64 {
+    let mut i = start;
+
+    while i < end {
+        hash ^= fold_case(bytes[i], fold_to_upper) as u64;
+        hash = hash.wrapping_mul(FNV_PRIME_64);
+        i += 1;
+    }
+    hash
+}
+
+/// Continues 32-bits fnv1a hash from the given state over the first len bytes.
+#[inline(always)]
+const fn fnv1a_continue_32(hash: u32, bytes: &[u8], len: usize, fold_to_upper: bool) -> u32 {
+    fnv1a_continue_range_32(hash, bytes, 0, len, fold_to_upper)
+}
+
+/// Continues 32-bits fnv1a hash from the given state over the bytes from start up-to end.
+#[inline(always)]
+const fn fnv1a_continue_range_32(mut hash: u32, bytes: &[u8], start: usize, end: usize, fold_to_upper: bool) -> u32 {
+    let mut i = start;
+
+    while i < end {
+        hash ^= fold_case(bytes[i], fold_to_upper) as u32;
+        hash = hash.wrapping_mul(FNV_PRIME_32);
+        i += 1;
+    }
+    hash
+}
+
+/// Continues 64-bits fnv1a hash from the given state over the bytes from start up-to end,
+/// folding only ascii lowercase letters to uppercase.
+#[inline(always)]
+const fn fnv1a_continue_range_letters_64(mut hash: u64, bytes: &[u8], start: usize, end: usize) -> u64 {
+    let mut i = start;
+
+    while i < end {
+        hash ^= fold_ascii_letter(bytes[i], true) as u64;
         hash = hash.wrapping_mul(FNV_PRIME_64);
         i += 1;
     }
     hash
 }
 
+/// Continues 32-bits fnv1a hash from the given state over the bytes from start up-to end,
+/// folding only ascii lowercase letters to uppercase.
+#[inline(always)]
+const fn fnv1a_continue_range_letters_32(mut hash: u32, bytes: &[u8], start: usize, end: usize) -> u32 {
+    let mut i = start;
+
+    while i < end {
+        hash ^= fold_ascii_letter(bytes[i], true) as u32;
+        hash = hash.wrapping_mul(FNV_PRIME_32);
+        i += 1;
+    }
+    hash
+}
+
+/// Continues 64-bits fnv1a hash from the given state over the first len bytes,
+/// folding only ascii lowercase letters to uppercase if fold_to_upper is set.
+#[inline(always)]
+const fn fnv1a_continue_letters_64(hash: u64, bytes: &[u8], len: usize, fold_to_upper: bool) -> u64 {
+    if fold_to_upper {
+        fnv1a_continue_range_letters_64(hash, bytes, 0, len)
+    }
+    else {
+        fnv1a_continue_64(hash, bytes, len, false)
+    }
+}
+
+/// Continues 32-bits fnv1a hash from the given state over the first len bytes,
+/// folding only ascii lowercase letters to uppercase if fold_to_upper is set.
+#[inline(always)]
+const fn fnv1a_continue_letters_32(hash: u32, bytes: &[u8], len: usize, fold_to_upper: bool) -> u32 {
+    if fold_to_upper {
+        fnv1a_continue_range_letters_32(hash, bytes, 0, len)
+    }
+    else {
+        fnv1a_continue_32(hash, bytes, len, false)
+    }
+}
+
+/// Computes 64-bits fnv1a hash of the given slice, or up-to limit if provided.
+/// If limit is zero or exceeds slice length, slice length is used instead.
+/// If fold_to_upper is set, the ascii case bit is cleared, hashing ascii lowercase letters as uppercase.
+#[deprecated(note = "use `fnv1a_hash_64_case_sensitive` or `fnv1a_hash_64_case_insensitive` instead")]
+pub const fn fnv1a_hash_64(bytes: &[u8], limit: Option<usize>, fold_to_upper: bool) -> u64 {
+    fnv1a_continue_64(FNV_OFFSET_BASIS_64, bytes, effective_len(bytes, limit), fold_to_upper)
+}
+
+/// Computes 32-bits fnv1a hash of the given slice, or up-to limit if provided.
+/// If limit is zero or exceeds slice length, slice length is used instead.
+/// If fold_to_upper is set, the ascii case bit is cleared, hashing ascii lowercase letters as uppercase.
+#[deprecated(note = "use `fnv1a_hash_32_case_sensitive` or `fnv1a_hash_32_case_insensitive` instead")]
+pub const fn fnv1a_hash_32(bytes: &[u8], limit: Option<usize>, fold_to_upper: bool) -> u32 {
+    fnv1a_continue_32(FNV_OFFSET_BASIS_32, bytes, effective_len(bytes, limit), fold_to_upper)
+}
+
+/// Computes 64-bits fnv1a hash of the given slice, or up-to limit if provided.
+/// If limit is zero or exceeds slice length, slice length is used instead.
+#[inline(always)]
+pub const fn fnv1a_hash_64_case_sensitive(bytes: &[u8], limit: Option<usize>) -> u64 {
+    fnv1a_continue_64(FNV_OFFSET_BASIS_64, bytes, effective_len(bytes, limit), false)
+}
+
+/// Computes 64-bits fnv1a hash of the given slice with the ascii case bit cleared, hashing ascii
+/// lowercase letters as uppercase, or up-to limit if provided. See `fnv1a_hash_64_ascii_case_insensitive`
+/// to fold only letters.
+/// If limit is zero or exceeds slice length, slice length is used instead.
+#[inline(always)]
+pub const fn fnv1a_hash_64_case_insensitive(bytes: &[u8], limit: Option<usize>) -> u64 {
+    fnv1a_continue_64(FNV_OFFSET_BASIS_64, bytes, effective_len(bytes, limit), true)
+}
+
 /// Computes 32-bits fnv1a hash of the given slice, or up-to limit if provided.
 /// If limit is zero or exceeds slice length, slice length is used instead.
-pub const fn fnv1a_hash_32(bytes: &[u8], limit: Option<usize>, case: bool) -> u32 {
+#[inline(always)]
+pub const fn fnv1a_hash_32_case_sensitive(bytes: &[u8], limit: Option<usize>) -> u32 {
+    fnv1a_continue_32(FNV_OFFSET_BASIS_32, bytes, effective_len(bytes, limit), false)
+}
+
+/// Computes 32-bits fnv1a hash of the given slice with the ascii case bit cleared, hashing ascii
+/// lowercase letters as uppercase, or up-to limit if provided. See `fnv1a_hash_32_ascii_case_insensitive`
+/// to fold only letters.
+/// If limit is zero or exceeds slice length, slice length is used instead.
+#[inline(always)]
+pub const fn fnv1a_hash_32_case_insensitive(bytes: &[u8], limit: Option<usize>) -> u32 {
+    fnv1a_continue_32(FNV_OFFSET_BASIS_32, bytes, effective_len(bytes, limit), true)
+}
+
+/// Computes 64-bits fnv1a hash of the given slice with only ascii lowercase letters hashed as
+/// uppercase, or up-to limit if provided. Unlike `fnv1a_hash_64_case_insensitive`, digits,
+/// punctuation and UTF-8 bytes are hashed unchanged.
+/// If limit is zero or exceeds slice length, slice length is used instead.
+#[inline(always)]
+pub const fn fnv1a_hash_64_ascii_case_insensitive(bytes: &[u8], limit: Option<usize>) -> u64 {
+    fnv1a_continue_range_letters_64(FNV_OFFSET_BASIS_64, bytes, 0, effective_len(bytes, limit))
+}
+
+/// Computes 32-bits fnv1a hash of the given slice with only ascii lowercase letters hashed as
+/// uppercase, or up-to limit if provided. Unlike `fnv1a_hash_32_case_insensitive`, digits,
+/// punctuation and UTF-8 bytes are hashed unchanged.
+/// If limit is zero or exceeds slice length, slice length is used instead.
+#[inline(always)]
+pub const fn fnv1a_hash_32_ascii_case_insensitive(bytes: &[u8], limit: Option<usize>) -> u32 {
+    fnv1a_continue_range_letters_32(FNV_OFFSET_BASIS_32, bytes, 0, effective_len(bytes, limit))
+}
+
+/// Computes 64-bits fnv1 hash of the given slice, or up-to limit if provided.
+/// Unlike fnv1a, the multiply happens before the XOR.
+/// If limit is zero or exceeds slice length, slice length is used instead.
+/// If fold_to_upper is set, only ascii lowercase letters are hashed as uppercase.
+pub const fn fnv1_hash_64(bytes: &[u8], limit: Option<usize>, fold_to_upper: bool) -> u64 {
+    let mut hash = FNV_OFFSET_BASIS_64;
+
+    let mut i = 0;
+    let len = effective_len(bytes, limit);
+
+    while i < len {
+        hash = hash.wrapping_mul(FNV_PRIME_64);
+        hash ^= fold_ascii_letter(bytes[i], fold_to_upper) as u64;
+        i += 1;
+    }
+    hash
+}
+
+/// Computes 32-bits fnv1 hash of the given slice, or up-to limit if provided.
+/// Unlike fnv1a, the multiply happens before the XOR.
+/// If limit is zero or exceeds slice length, slice length is used instead.
+/// If fold_to_upper is set, only ascii lowercase letters are hashed as uppercase.
+pub const fn fnv1_hash_32(bytes: &[u8], limit: Option<usize>, fold_to_upper: bool) -> u32 {
     let mut hash = FNV_OFFSET_BASIS_32;
 
     let mut i = 0;
-    let len = match limit {
-        Some(v) => {
-            if v <= bytes.len() && v > 0 {
-                v
-            } else {
-                bytes.len()
-            }
-        }
-        None => bytes.len(),
-    };
+    let len = effective_len(bytes, limit);
 
     while i < len {
-        let value = if case && (bytes[i] & ASCII_CASE_MASK == ASCII_CASE_MASK) {
-            bytes[i] ^ ASCII_CASE_MASK
-        }
-        else {
-            bytes[i]
-        };
-        hash ^= value as u32;
         hash = hash.wrapping_mul(FNV_PRIME_32);
+        hash ^= fold_ascii_letter(bytes[i], fold_to_upper) as u32;
         i += 1;
     }
     hash
@@ -76,22 +297,43 @@ pub const fn fnv1a_hash_32(bytes: &[u8], limit: Option<usize>, case: bool) -> u3
 /// If limit is zero or exceeds slice length, slice length is used instead.
 #[inline(always)]
 pub const fn fnv1a_hash_16_xor(bytes: &[u8], limit: Option<usize>) -> u16 {
-    let bytes = fnv1a_hash_32(bytes, limit, false).to_ne_bytes();
-    let upper: u16 = u16::from_ne_bytes([bytes[0], bytes[1]]);
-    let lower: u16 = u16::from_ne_bytes([bytes[2], bytes[3]]);
-    upper ^ lower
+    xor_fold_16(fnv1a_hash_32_case_sensitive(bytes, limit))
+}
+
+/// Computes case-insensitive 32-bits fnv1a hash and XORs higher and lower 16-bits.
+/// The ascii case bit is cleared as with `fnv1a_hash_32_case_insensitive`.
+/// If limit is zero or exceeds slice length, slice length is used instead.
+#[inline(always)]
+pub const fn fnv1a_hash_16_xor_case_insensitive(bytes: &[u8], limit: Option<usize>) -> u16 {
+    xor_fold_16(fnv1a_hash_32_case_insensitive(bytes, limit))
+}
+
+/// Computes 32-bits fnv1a hash and XORs all four bytes together.
+/// This results in a 8-bits hash value.
+/// Up to limit if provided, otherwise slice length.
+/// If limit is zero or exceeds slice length, slice length is used instead.
+#[inline(always)]
+pub const fn fnv1a_hash_8_xor(bytes: &[u8], limit: Option<usize>) -> u8 {
+    let bytes = fnv1a_hash_32_case_sensitive(bytes, limit).to_ne_bytes();
+    bytes[0] ^ bytes[1] ^ bytes[2] ^ bytes[3]
+}
+
+/// Computes 128-bit fnv1a hash from a str.
+#[inline(always)]
+pub const fn fnv1a_hash_str_128(input: &str) -> u128 {
+    fnv1a_hash_128(input.as_bytes(), None, false)
 }
 
 /// Computes 64-bit fnv1a hash from a str.
 #[inline(always)]
 pub const fn fnv1a_hash_str_64(input: &str) -> u64 {
-    fnv1a_hash_64(input.as_bytes(), None, false)
+    fnv1a_hash_64_case_sensitive(input.as_bytes(), None)
 }
 
 /// Computes 32-bit fnv1a hash from a str.
 #[inline(always)]
 pub const fn fnv1a_hash_str_32(input: &str) -> u32 {
-    fnv1a_hash_32(input.as_bytes(), None, false)
+    fnv1a_hash_32_case_sensitive(input.as_bytes(), None)
 }
 
 /// Computes 16-bit fnv1a hash from a str using XOR folding.
@@ -100,21 +342,5678 @@ pub const fn fnv1a_hash_str_16_xor(input: &str) -> u16 {
     fnv1a_hash_16_xor(input.as_bytes(), None)
 }
 
+/// Computes 64-bit case-insensitive fnv1a hash from a str.
+/// Clears the ascii case bit as in 1.0, see `fnv1a_hash_64_case_insensitive`.
+#[inline(always)]
+pub const fn fnv1a_hash_str_case_64(input: &str) -> u64 {
+    fnv1a_hash_64_case_insensitive(input.as_bytes(), None)
+}
 
-#[test]
-fn fnv1a_test_case_comparison() {
-    let bytes = ['A' as u8, 'B' as u8];
-    assert_eq!(fnv1a_hash_64(&bytes, None, false), fnv1a_hash_64(&bytes, None, true));
-    assert_eq!(fnv1a_hash_32(&bytes, None, false), fnv1a_hash_32(&bytes, None, true));
+/// Computes 32-bit case-insensitive fnv1a hash from a str.
+/// Clears the ascii case bit as in 1.0, see `fnv1a_hash_32_case_insensitive`.
+#[inline(always)]
+pub const fn fnv1a_hash_str_case_32(input: &str) -> u32 {
+    fnv1a_hash_32_case_insensitive(input.as_bytes(), None)
+}
 
-    let bytes = ['a' as u8, 'B' as u8];
-    assert_ne!(fnv1a_hash_64(&bytes, None, false), fnv1a_hash_64(&bytes, None, true));
-    assert_ne!(fnv1a_hash_32(&bytes, None, false), fnv1a_hash_32(&bytes, None, true));
+/// Computes 16-bit case-insensitive fnv1a hash from a str using XOR folding.
+#[inline(always)]
+pub const fn fnv1a_hash_str_16_xor_case(input: &str) -> u16 {
+    fnv1a_hash_16_xor_case_insensitive(input.as_bytes(), None)
+}
 
-    let bytes = ['a' as u8, 'B' as u8];
-    let comparison = ['A' as u8, 'B' as u8];
-    assert_eq!(fnv1a_hash_64(&bytes, None, true), fnv1a_hash_64(&comparison, None, true));
-    assert_eq!(fnv1a_hash_32(&bytes, None, true), fnv1a_hash_32(&comparison, None, true));
+/// Computes 8-bit fnv1a hash from a str using XOR folding.
+#[inline(always)]
+pub const fn fnv1a_hash_str_8_xor(input: &str) -> u8 {
+    fnv1a_hash_8_xor(input.as_bytes(), None)
+}
+
+/// Computes 64-bit fnv1 hash from a str.
+#[inline(always)]
+pub const fn fnv1_hash_str_64(input: &str) -> u64 {
+    fnv1_hash_64(input.as_bytes(), None, false)
+}
+
+/// Computes 32-bit fnv1 hash from a str.
+#[inline(always)]
+pub const fn fnv1_hash_str_32(input: &str) -> u32 {
+    fnv1_hash_32(input.as_bytes(), None, false)
+}
+
+/// Computes 64-bit fnv1a hash of a string literal in a const context.
+/// The hash is always evaluated at compile time.
+/// The macro expands to a block expression, which is not a valid pattern, so bind the
+/// result to a `const` item to match on it, or compare against it in a match guard.
+/// The `const_fnv1a_hash_macros::fnv1a_64!` proc macro expands to a plain `u64` literal
+/// instead, so it can be used directly as a match pattern.
+///
+/// ```
+/// const FOO: u64 = const_fnv1a_hash::const_fnv1a_64!("foo");
+///
+/// match const_fnv1a_hash::fnv1a_hash_str_64("foo") {
+///     FOO => {}
+///     _ => unreachable!(),
+/// }
+/// ```
+#[macro_export]
+macro_rules! const_fnv1a_64 {
+    ($input:literal) => {{
+        const HASH: u64 = $crate::fnv1a_hash_str_64($input);
+        HASH
+    }};
+}
+
+/// Computes 32-bit fnv1a hash of a string literal in a const context.
+/// The hash is always evaluated at compile time.
+/// The macro expands to a block expression, which is not a valid pattern, so bind the
+/// result to a `const` item to match on it, as shown for `const_fnv1a_64!`.
+/// Use the `const_fnv1a_hash_macros::fnv1a_32!` proc macro to hash in a match pattern directly.
+#[macro_export]
+macro_rules! const_fnv1a_32 {
+    ($input:literal) => {{
+        const HASH: u32 = $crate::fnv1a_hash_str_32($input);
+        HASH
+    }};
+}
+
+
+/// Incremental 64-bits fnv1a hasher.
+/// Hashing several chunks yields the same result as hashing their concatenation.
+#[derive(Clone, Copy, Debug)]
+pub struct Fnv1aHasher64 {
+    state: u64,
+}
+
+impl Fnv1aHasher64 {
+    /// Creates a new hasher starting from the offset basis.
+    #[inline(always)]
+    pub const fn new() -> Self {
+        Self { state: FNV_OFFSET_BASIS_64 }
+    }
+
+    /// Feeds the given bytes into the hasher.
+    #[inline(always)]
+    pub const fn update(self, bytes: &[u8]) -> Self {
+        Self { state: fnv1a_continue_64(self.state, bytes, bytes.len(), false) }
+    }
+
+    /// Returns the hash of all bytes fed so far.
+    #[inline(always)]
+    pub const fn finalize(self) -> u64 {
+        self.state
+    }
+}
+
+impl Default for Fnv1aHasher64 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Incremental 64-bits case-insensitive fnv1a hasher.
+/// Only ascii lowercase letters are folded, as with `fnv1a_hash_64_ascii_case_insensitive`.
+/// Hashing several chunks yields the same result as hashing their concatenation.
+#[derive(Clone, Copy, Debug)]
+pub struct Fnv1aHasher64Case {
+    state: u64,
+}
+
+impl Fnv1aHasher64Case {
+    /// Creates a new hasher starting from the offset basis.
+    #[inline(always)]
+    pub const fn new() -> Self {
+        Self { state: FNV_OFFSET_BASIS_64 }
+    }
+
+    /// Feeds the given bytes into the hasher, folding ascii lowercase letters to uppercase.
+    #[inline(always)]
+    pub const fn update(self, bytes: &[u8]) -> Self {
+        Self { state: fnv1a_continue_letters_64(self.state, bytes, bytes.len(), true) }
+    }
+
+    /// Returns the hash of all bytes fed so far.
+    #[inline(always)]
+    pub const fn finalize(self) -> u64 {
+        self.state
+    }
+}
+
+impl Default for Fnv1aHasher64Case {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Incremental 32-bits fnv1a hasher.
+/// Hashing several chunks yields the same result as hashing their concatenation.
+#[derive(Clone, Copy, Debug)]
+pub struct Fnv1aHasher32 {
+    state: u32,
+}
+
+impl Fnv1aHasher32 {
+    /// Creates a new hasher starting from the offset basis.
+    #[inline(always)]
+    pub const fn new() -> Self {
+        Self { state: FNV_OFFSET_BASIS_32 }
+    }
+
+    /// Feeds the given bytes into the hasher.
+    #[inline(always)]
+    pub const fn update(self, bytes: &[u8]) -> Self {
+        Self { state: fnv1a_continue_32(self.state, bytes, bytes.len(), false) }
+    }
+
+    /// Returns the hash of all bytes fed so far.
+    #[inline(always)]
+    pub const fn finalize(self) -> u32 {
+        self.state
+    }
+}
+
+impl Default for Fnv1aHasher32 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Incremental 32-bits case-insensitive fnv1a hasher.
+/// Only ascii lowercase letters are folded, as with `fnv1a_hash_32_ascii_case_insensitive`.
+/// Hashing several chunks yields the same result as hashing their concatenation.
+#[derive(Clone, Copy, Debug)]
+pub struct Fnv1aHasher32Case {
+    state: u32,
+}
+
+impl Fnv1aHasher32Case {
+    /// Creates a new hasher starting from the offset basis.
+    #[inline(always)]
+    pub const fn new() -> Self {
+        Self { state: FNV_OFFSET_BASIS_32 }
+    }
 
+    /// Feeds the given bytes into the hasher, folding ascii lowercase letters to uppercase.
+    #[inline(always)]
+    pub const fn update(self, bytes: &[u8]) -> Self {
+        Self { state: fnv1a_continue_letters_32(self.state, bytes, bytes.len(), true) }
+    }
+
+    /// Returns the hash of all bytes fed so far.
+    #[inline(always)]
+    pub const fn finalize(self) -> u32 {
+        self.state
+    }
+}
+
+impl Default for Fnv1aHasher32Case {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+
+/// 64-bits fnv1a `core::hash::Hasher`.
+/// Successive writes are hashed as if they were concatenated.
+#[cfg(feature = "hash")]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct FnvHasher {
+    hasher: Fnv1aHasher64,
+}
+
+#[cfg(feature = "hash")]
+impl core::hash::Hasher for FnvHasher {
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        self.hasher = self.hasher.update(bytes);
+    }
+
+    #[inline]
+    fn finish(&self) -> u64 {
+        self.hasher.finalize()
+    }
+}
+
+/// `core::hash::BuildHasher` creating `FnvHasher` instances.
+#[cfg(feature = "hash")]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct FnvBuildHasher;
+
+#[cfg(feature = "hash")]
+impl core::hash::BuildHasher for FnvBuildHasher {
+    type Hasher = FnvHasher;
+
+    #[inline]
+    fn build_hasher(&self) -> FnvHasher {
+        FnvHasher::default()
+    }
+}
+
+/// `std::collections::HashMap` using `FnvBuildHasher`.
+#[cfg(feature = "std")]
+pub type FnvHashMap<K, V> = std::collections::HashMap<K, V, FnvBuildHasher>;
+
+/// `std::collections::HashSet` using `FnvBuildHasher`.
+#[cfg(feature = "std")]
+pub type FnvHashSet<K> = std::collections::HashSet<K, FnvBuildHasher>;
+
+
+/// Computes 64-bit fnv1a hash of a u8 using its little-endian bytes.
+#[inline(always)]
+pub const fn fnv1a_hash_u8_64(value: u8) -> u64 {
+    fnv1a_hash_64_case_sensitive(&value.to_le_bytes(), None)
+}
+
+/// Computes 32-bit fnv1a hash of a u8 using its little-endian bytes.
+#[inline(always)]
+pub const fn fnv1a_hash_u8_32(value: u8) -> u32 {
+    fnv1a_hash_32_case_sensitive(&value.to_le_bytes(), None)
+}
+
+/// Computes 64-bit fnv1a hash of a u16 using its little-endian bytes.
+#[inline(always)]
+pub const fn fnv1a_hash_u16_64(value: u16) -> u64 {
+    fnv1a_hash_64_case_sensitive(&value.to_le_bytes(), None)
+}
 
-}
\ No newline at end of file
+/// Computes 32-bit fnv1a hash of a u16 using its little-endian bytes.
+#[inline(always)]
+pub const fn fnv1a_hash_u16_32(value: u16) -> u32 {
+    fnv1a_hash_32_case_sensitive(&value.to_le_bytes(), None)
+}
+
+/// Computes 64-bit fnv1a hash of a u32 using its little-endian bytes.
+#[inline(always)]
+pub const fn fnv1a_hash_u32_64(value: u32) -> u64 {
+    fnv1a_hash_64_case_sensitive(&value.to_le_bytes(), None)
+}
+
+/// Computes 32-bit fnv1a hash of a u32 using its little-endian bytes.
+#[inline(always)]
+pub const fn fnv1a_hash_u32_32(value: u32) -> u32 {
+    fnv1a_hash_32_case_sensitive(&value.to_le_bytes(), None)
+}
+
+/// Computes 64-bit fnv1a hash of a u64 using its little-endian bytes.
+#[inline(always)]
+pub const fn fnv1a_hash_u64_64(value: u64) -> u64 {
+    fnv1a_hash_64_case_sensitive(&value.to_le_bytes(), None)
+}
+
+/// Computes 32-bit fnv1a hash of a u64 using its little-endian bytes.
+#[inline(always)]
+pub const fn fnv1a_hash_u64_32(value: u64) -> u32 {
+    fnv1a_hash_32_case_sensitive(&value.to_le_bytes(), None)
+}
+
+/// Computes 64-bit fnv1a hash of a u128 using its little-endian bytes.
+#[inline(always)]
+pub const fn fnv1a_hash_u128_64(value: u128) -> u64 {
+    fnv1a_hash_64_case_sensitive(&value.to_le_bytes(), None)
+}
+
+/// Computes 32-bit fnv1a hash of a u128 using its little-endian bytes.
+#[inline(always)]
+pub const fn fnv1a_hash_u128_32(value: u128) -> u32 {
+    fnv1a_hash_32_case_sensitive(&value.to_le_bytes(), None)
+}
+
+/// Computes 64-bit fnv1a hash of a usize using its little-endian bytes.
+#[inline(always)]
+pub const fn fnv1a_hash_usize_64(value: usize) -> u64 {
+    fnv1a_hash_64_case_sensitive(&value.to_le_bytes(), None)
+}
+
+/// Computes 32-bit fnv1a hash of a usize using its little-endian bytes.
+#[inline(always)]
+pub const fn fnv1a_hash_usize_32(value: usize) -> u32 {
+    fnv1a_hash_32_case_sensitive(&value.to_le_bytes(), None)
+}
+
+/// Computes 64-bit fnv1a hash of a i8 using its little-endian bytes.
+#[inline(always)]
+pub const fn fnv1a_hash_i8_64(value: i8) -> u64 {
+    fnv1a_hash_64_case_sensitive(&value.to_le_bytes(), None)
+}
+
+/// Computes 32-bit fnv1a hash of a i8 using its little-endian bytes.
+#[inline(always)]
+pub const fn fnv1a_hash_i8_32(value: i8) -> u32 {
+    fnv1a_hash_32_case_sensitive(&value.to_le_bytes(), None)
+}
+
+/// Computes 64-bit fnv1a hash of a i16 using its little-endian bytes.
+#[inline(always)]
+pub const fn fnv1a_hash_i16_64(value: i16) -> u64 {
+    fnv1a_hash_64_case_sensitive(&value.to_le_bytes(), None)
+}
+
+/// Computes 32-bit fnv1a hash of a i16 using its little-endian bytes.
+#[inline(always)]
+pub const fn fnv1a_hash_i16_32(value: i16) -> u32 {
+    fnv1a_hash_32_case_sensitive(&value.to_le_bytes(), None)
+}
+
+/// Computes 64-bit fnv1a hash of a i32 using its little-endian bytes.
+#[inline(always)]
+pub const fn fnv1a_hash_i32_64(value: i32) -> u64 {
+    fnv1a_hash_64_case_sensitive(&value.to_le_bytes(), None)
+}
+
+/// Computes 32-bit fnv1a hash of a i32 using its little-endian bytes.
+#[inline(always)]
+pub const fn fnv1a_hash_i32_32(value: i32) -> u32 {
+    fnv1a_hash_32_case_sensitive(&value.to_le_bytes(), None)
+}
+
+/// Computes 64-bit fnv1a hash of a i64 using its little-endian bytes.
+#[inline(always)]
+pub const fn fnv1a_hash_i64_64(value: i64) -> u64 {
+    fnv1a_hash_64_case_sensitive(&value.to_le_bytes(), None)
+}
+
+/// Computes 32-bit fnv1a hash of a i64 using its little-endian bytes.
+#[inline(always)]
+pub const fn fnv1a_hash_i64_32(value: i64) -> u32 {
+    fnv1a_hash_32_case_sensitive(&value.to_le_bytes(), None)
+}
+
+/// Computes 64-bit fnv1a hash of a i128 using its little-endian bytes.
+#[inline(always)]
+pub const fn fnv1a_hash_i128_64(value: i128) -> u64 {
+    fnv1a_hash_64_case_sensitive(&value.to_le_bytes(), None)
+}
+
+/// Computes 32-bit fnv1a hash of a i128 using its little-endian bytes.
+#[inline(always)]
+pub const fn fnv1a_hash_i128_32(value: i128) -> u32 {
+    fnv1a_hash_32_case_sensitive(&value.to_le_bytes(), None)
+}
+
+/// Computes 64-bit fnv1a hash of a isize using its little-endian bytes.
+#[inline(always)]
+pub const fn fnv1a_hash_isize_64(value: isize) -> u64 {
+    fnv1a_hash_64_case_sensitive(&value.to_le_bytes(), None)
+}
+
+/// Computes 32-bit fnv1a hash of a isize using its little-endian bytes.
+#[inline(always)]
+pub const fn fnv1a_hash_isize_32(value: isize) -> u32 {
+    fnv1a_hash_32_case_sensitive(&value.to_le_bytes(), None)
+}
+
+
+/// Computes 64-bits fnv1a hash of all parts as if they were concatenated.
+/// If fold_to_upper is set, only ascii lowercase letters are hashed as uppercase.
+pub const fn fnv1a_hash_64_chain(parts: &[&[u8]], fold_to_upper: bool) -> u64 {
+    let mut hash = FNV_OFFSET_BASIS_64;
+
+    let mut i = 0;
+    while i < parts.len() {
+        hash = fnv1a_continue_letters_64(hash, parts[i], parts[i].len(), fold_to_upper);
+        i += 1;
+    }
+    hash
+}
+
+/// Computes 32-bits fnv1a hash of all parts as if they were concatenated.
+/// If fold_to_upper is set, only ascii lowercase letters are hashed as uppercase.
+pub const fn fnv1a_hash_32_chain(parts: &[&[u8]], fold_to_upper: bool) -> u32 {
+    let mut hash = FNV_OFFSET_BASIS_32;
+
+    let mut i = 0;
+    while i < parts.len() {
+        hash = fnv1a_continue_letters_32(hash, parts[i], parts[i].len(), fold_to_upper);
+        i += 1;
+    }
+    hash
+}
+
+
+/// Computes 64-bits fnv1a hash starting from seed instead of the offset basis.
+/// If limit is zero or exceeds slice length, slice length is used instead.
+/// If fold_to_upper is set, only ascii lowercase letters are hashed as uppercase.
+#[inline(always)]
+pub const fn fnv1a_hash_64_seeded(bytes: &[u8], limit: Option<usize>, fold_to_upper: bool, seed: u64) -> u64 {
+    fnv1a_continue_letters_64(seed, bytes, effective_len(bytes, limit), fold_to_upper)
+}
+
+/// Computes 32-bits fnv1a hash starting from seed instead of the offset basis.
+/// If limit is zero or exceeds slice length, slice length is used instead.
+/// If fold_to_upper is set, only ascii lowercase letters are hashed as uppercase.
+#[inline(always)]
+pub const fn fnv1a_hash_32_seeded(bytes: &[u8], limit: Option<usize>, fold_to_upper: bool, seed: u32) -> u32 {
+    fnv1a_continue_letters_32(seed, bytes, effective_len(bytes, limit), fold_to_upper)
+}
+
+
+/// Computes 64-bits fnv1a hash of a pair of slices.
+/// Each slice is prefixed by its length as 8 little-endian bytes,
+/// so moving bytes between a and b changes the hash.
+#[inline(always)]
+pub const fn fnv1a_hash_64_pair(a: &[u8], b: &[u8]) -> u64 {
+    fnv1a_pair_64(a, b, false)
+}
+
+/// Computes 64-bits fnv1a hash of a length-prefixed pair, folding only the slices themselves.
+const fn fnv1a_pair_64(a: &[u8], b: &[u8], fold_to_upper: bool) -> u64 {
+    let a_len = (a.len() as u64).to_le_bytes();
+    let b_len = (b.len() as u64).to_le_bytes();
+
+    let mut hash = fnv1a_continue_64(FNV_OFFSET_BASIS_64, &a_len, a_len.len(), false);
+    hash = fnv1a_continue_letters_64(hash, a, a.len(), fold_to_upper);
+    hash = fnv1a_continue_64(hash, &b_len, b_len.len(), false);
+    fnv1a_continue_letters_64(hash, b, b.len(), fold_to_upper)
+}
+
+
+/// Computes 64-bits fnv1a hash of a fixed-size byte array.
+#[inline(always)]
+pub const fn fnv1a_hash_fixed_64<const N: usize>(bytes: &[u8; N]) -> u64 {
+    fnv1a_hash_64_case_sensitive(bytes, None)
+}
+
+/// Computes 32-bits fnv1a hash of a fixed-size byte array.
+#[inline(always)]
+pub const fn fnv1a_hash_fixed_32<const N: usize>(bytes: &[u8; N]) -> u32 {
+    fnv1a_hash_32_case_sensitive(bytes, None)
+}
+
+
+/// Computes 64-bit fnv1a hash of all str parts as if they were concatenated.
+pub const fn fnv1a_hash_str_concat_64(parts: &[&str]) -> u64 {
+    let mut hash = FNV_OFFSET_BASIS_64;
+
+    let mut i = 0;
+    while i < parts.len() {
+        let bytes = parts[i].as_bytes();
+        hash = fnv1a_continue_64(hash, bytes, bytes.len(), false);
+        i += 1;
+    }
+    hash
+}
+
+/// Computes 64-bit case-insensitive fnv1a hash of all str parts as if they were concatenated.
+/// Only ascii lowercase letters are folded, as with `fnv1a_hash_64_ascii_case_insensitive`.
+pub const fn fnv1a_hash_str_concat_case_64(parts: &[&str]) -> u64 {
+    let mut hash = FNV_OFFSET_BASIS_64;
+
+    let mut i = 0;
+    while i < parts.len() {
+        let bytes = parts[i].as_bytes();
+        hash = fnv1a_continue_letters_64(hash, bytes, bytes.len(), true);
+        i += 1;
+    }
+    hash
+}
+
+/// Computes 32-bit fnv1a hash of all str parts as if they were concatenated.
+pub const fn fnv1a_hash_str_concat_32(parts: &[&str]) -> u32 {
+    let mut hash = FNV_OFFSET_BASIS_32;
+
+    let mut i = 0;
+    while i < parts.len() {
+        let bytes = parts[i].as_bytes();
+        hash = fnv1a_continue_32(hash, bytes, bytes.len(), false);
+        i += 1;
+    }
+    hash
+}
+
+/// Computes 32-bit case-insensitive fnv1a hash of all str parts as if they were concatenated.
+/// Only ascii lowercase letters are folded, as with `fnv1a_hash_32_ascii_case_insensitive`.
+pub const fn fnv1a_hash_str_concat_case_32(parts: &[&str]) -> u32 {
+    let mut hash = FNV_OFFSET_BASIS_32;
+
+    let mut i = 0;
+    while i < parts.len() {
+        let bytes = parts[i].as_bytes();
+        hash = fnv1a_continue_letters_32(hash, bytes, bytes.len(), true);
+        i += 1;
+    }
+    hash
+}
+
+
+/// Encodes the char as UTF-8, returning the buffer and the amount of bytes used.
+const fn encode_utf8(c: char) -> ([u8; 4], usize) {
+    let code = c as u32;
+    if code < 0x80 {
+        ([code as u8, 0, 0, 0], 1)
+    } else if code < 0x800 {
+        ([0xC0 | (code >> 6) as u8, 0x80 | (code & 0x3F) as u8, 0, 0], 2)
+    } else if code < 0x10000 {
+        (
+            [
+                0xE0 | (code >> 12) as u8,
+                0x80 | ((code >> 6) & 0x3F) as u8,
+                0x80 | (code & 0x3F) as u8,
+                0,
+            ],
+            3,
+        )
+    } else {
+        (
+            [
+                0xF0 | (code >> 18) as u8,
+                0x80 | ((code >> 12) & 0x3F) as u8,
+                0x80 | ((code >> 6) & 0x3F) as u8,
+                0x80 | (code & 0x3F) as u8,
+            ],
+            4,
+        )
+    }
+}
+
+/// Computes 64-bit fnv1a hash of a char using its UTF-8 encoding.
+#[inline(always)]
+pub const fn fnv1a_hash_char_64(c: char) -> u64 {
+    let (bytes, len) = encode_utf8(c);
+    fnv1a_continue_64(FNV_OFFSET_BASIS_64, &bytes, len, false)
+}
+
+/// Computes 32-bit fnv1a hash of a char using its UTF-8 encoding.
+#[inline(always)]
+pub const fn fnv1a_hash_char_32(c: char) -> u32 {
+    let (bytes, len) = encode_utf8(c);
+    fnv1a_continue_32(FNV_OFFSET_BASIS_32, &bytes, len, false)
+}
+
+
+/// Computes 64-bits fnv1a hash of data prefixed by a domain separation tag.
+/// The prefix is fed before data without any separator.
+#[inline(always)]
+pub const fn fnv1a_hash_with_prefix_64(prefix: &[u8], data: &[u8]) -> u64 {
+    let hash = fnv1a_continue_64(FNV_OFFSET_BASIS_64, prefix, prefix.len(), false);
+    fnv1a_continue_64(hash, data, data.len(), false)
+}
+
+/// Computes 64-bit fnv1a hash of a str prefixed by a domain separation tag.
+#[inline(always)]
+pub const fn fnv1a_hash_str_with_prefix_64(prefix: &str, data: &str) -> u64 {
+    fnv1a_hash_with_prefix_64(prefix.as_bytes(), data.as_bytes())
+}
+
+
+/// Computes 64-bits fnv1a hash of every stride-th byte, starting at offset.
+/// If stride is zero or offset exceeds slice length, nothing is hashed.
+pub const fn fnv1a_hash_64_skip(bytes: &[u8], stride: usize, offset: usize) -> u64 {
+    let mut hash = FNV_OFFSET_BASIS_64;
+    if stride == 0 {
+        return hash;
+    }
+
+    let mut i = offset;
+    while i < bytes.len() {
+        hash ^= bytes[i] as u64;
+        hash = hash.wrapping_mul(FNV_PRIME_64);
+        match i.checked_add(stride) {
+            Some(next) => i = next,
+            None => break,
+        }
+    }
+    hash
+}
+
+/// Computes 32-bits fnv1a hash of every stride-th byte, starting at offset.
+/// If stride is zero or offset exceeds slice length, nothing is hashed.
+pub const fn fnv1a_hash_32_skip(bytes: &[u8], stride: usize, offset: usize) -> u32 {
+    let mut hash = FNV_OFFSET_BASIS_32;
+    if stride == 0 {
+        return hash;
+    }
+
+    let mut i = offset;
+    while i < bytes.len() {
+        hash ^= bytes[i] as u32;
+        hash = hash.wrapping_mul(FNV_PRIME_32);
+        match i.checked_add(stride) {
+            Some(next) => i = next,
+            None => break,
+        }
+    }
+    hash
+}
+
+
+/// Folds Latin-1 uppercase letters to their lowercase counterparts.
+/// Ascii lowercase letters are folded to uppercase, other ascii bytes are unchanged.
+#[inline(always)]
+const fn fold_latin1(byte: u8) -> u8 {
+    match byte {
+        0xC0..=0xD6 | 0xD8..=0xDE => byte | ASCII_CASE_MASK,
+        0x00..=0x7F => fold_ascii_letter(byte, true),
+        _ => byte,
+    }
+}
+
+/// Computes 64-bits case-insensitive fnv1a hash of the given Latin-1 slice, or up-to limit if provided.
+/// Latin-1 letters 0xC0-0xDE are folded onto 0xE0-0xFE, skipping the 0xD7 and 0xF7 signs.
+/// If limit is zero or exceeds slice length, slice length is used instead.
+pub const fn fnv1a_hash_case_latin1_64(bytes: &[u8], limit: Option<usize>) -> u64 {
+    let mut hash = FNV_OFFSET_BASIS_64;
+
+    let mut i = 0;
+    let len = effective_len(bytes, limit);
+
+    while i < len {
+        hash ^= fold_latin1(bytes[i]) as u64;
+        hash = hash.wrapping_mul(FNV_PRIME_64);
+        i += 1;
+    }
+    hash
+}
+
+
+/// Computes 32-bits fnv1a hash and maps it onto the range [0, n).
+/// Uses multiply-shift range reduction instead of modulo, so the
+/// high bits of the hash select the index.
+#[inline(always)]
+pub const fn fnv1a_hash_32_reduce(bytes: &[u8], n: u32) -> u32 {
+    let hash = fnv1a_hash_32_case_sensitive(bytes, None);
+    ((hash as u64 * n as u64) >> 32) as u32
+}
+
+/// Computes 64-bits fnv1a hash and maps it onto the range [0, n).
+/// Uses multiply-shift range reduction instead of modulo, so the
+/// high bits of the hash select the index.
+#[inline(always)]
+pub const fn fnv1a_hash_64_reduce(bytes: &[u8], n: u64) -> u64 {
+    let hash = fnv1a_hash_64_case_sensitive(bytes, None);
+    ((hash as u128 * n as u128) >> 64) as u64
+}
+
+
+/// Asserts at compile time that two byte slices have the same 64-bit fnv1a hash.
+///
+/// ```
+/// const_fnv1a_hash::const_assert_hash_eq!(b"foo", b"foo");
+/// ```
+///
+/// ```compile_fail
+/// const_fnv1a_hash::const_assert_hash_eq!(b"foo", b"bar");
+/// ```
+#[macro_export]
+macro_rules! const_assert_hash_eq {
+    ($a:expr, $b:expr $(,)?) => {
+        const _: () = assert!(
+            $crate::fnv1a_hash_64_case_sensitive($a, None) == $crate::fnv1a_hash_64_case_sensitive($b, None)
+        );
+    };
+}
+
+/// Asserts at compile time that two byte slices have different 64-bit fnv1a hashes.
+///
+/// ```
+/// const_fnv1a_hash::const_assert_hash_ne!(b"foo", b"bar");
+/// ```
+///
+/// ```compile_fail
+/// const_fnv1a_hash::const_assert_hash_ne!(b"foo", b"foo");
+/// ```
+#[macro_export]
+macro_rules! const_assert_hash_ne {
+    ($a:expr, $b:expr $(,)?) => {
+        const _: () = assert!(
+            $crate::fnv1a_hash_64_case_sensitive($a, None) != $crate::fnv1a_hash_64_case_sensitive($b, None)
+        );
+    };
+}
+
+/// Asserts at compile time that two strs have the same 64-bit fnv1a hash.
+///
+/// ```
+/// const_fnv1a_hash::const_assert_str_hash_eq!("foo", "foo");
+/// ```
+///
+/// ```compile_fail
+/// const_fnv1a_hash::const_assert_str_hash_eq!("foo", "bar");
+/// ```
+#[macro_export]
+macro_rules! const_assert_str_hash_eq {
+    ($a:expr, $b:expr $(,)?) => {
+        const _: () = assert!($crate::fnv1a_hash_str_64($a) == $crate::fnv1a_hash_str_64($b));
+    };
+}
+
+
+/// Computes 64-bit fnv1a hash of a u16 slice using the little-endian bytes of each value.
+pub const fn fnv1a_hash_u16_slice_64(values: &[u16]) -> u64 {
+    let mut hash = FNV_OFFSET_BASIS_64;
+
+    let mut i = 0;
+    while i < values.len() {
+        let bytes = values[i].to_le_bytes();
+        hash = fnv1a_continue_64(hash, &bytes, bytes.len(), false);
+        i += 1;
+    }
+    hash
+}
+
+/// Computes 64-bit fnv1a hash of a u32 slice using the little-endian bytes of each value.
+pub const fn fnv1a_hash_u32_slice_64(values: &[u32]) -> u64 {
+    let mut hash = FNV_OFFSET_BASIS_64;
+
+    let mut i = 0;
+    while i < values.len() {
+        let bytes = values[i].to_le_bytes();
+        hash = fnv1a_continue_64(hash, &bytes, bytes.len(), false);
+        i += 1;
+    }
+    hash
+}
+
+/// Computes 64-bit fnv1a hash of a u64 slice using the little-endian bytes of each value.
+pub const fn fnv1a_hash_u64_slice_64(values: &[u64]) -> u64 {
+    let mut hash = FNV_OFFSET_BASIS_64;
+
+    let mut i = 0;
+    while i < values.len() {
+        let bytes = values[i].to_le_bytes();
+        hash = fnv1a_continue_64(hash, &bytes, bytes.len(), false);
+        i += 1;
+    }
+    hash
+}
+
+
+/// Computes 64-bit fnv1a hash of a f32 using the little-endian bytes of its bit pattern.
+/// All NaN values hash as `u32::MAX` and negative zero hashes as positive zero.
+pub const fn fnv1a_hash_f32_64(value: f32) -> u64 {
+    let bits = if value.is_nan() {
+        u32::MAX
+    } else if value == 0.0 {
+        0
+    } else {
+        value.to_bits()
+    };
+    fnv1a_hash_64_case_sensitive(&bits.to_le_bytes(), None)
+}
+
+/// Computes 64-bit fnv1a hash of a f64 using the little-endian bytes of its bit pattern.
+/// All NaN values hash as `u64::MAX` and negative zero hashes as positive zero.
+pub const fn fnv1a_hash_f64_64(value: f64) -> u64 {
+    let bits = if value.is_nan() {
+        u64::MAX
+    } else if value == 0.0 {
+        0
+    } else {
+        value.to_bits()
+    };
+    fnv1a_hash_64_case_sensitive(&bits.to_le_bytes(), None)
+}
+
+
+/// Clamps limit to the slice length. Unlike `effective_len`, a zero limit hashes nothing.
+#[inline(always)]
+const fn clamp_len(bytes: &[u8], limit: usize) -> usize {
+    if limit < bytes.len() {
+        limit
+    } else {
+        bytes.len()
+    }
+}
+
+/// Computes 64-bit fnv1a hash of the first limit bytes of a str.
+/// If limit exceeds str length, the whole str is hashed.
+#[inline(always)]
+pub const fn fnv1a_hash_str_64_limit(input: &str, limit: usize) -> u64 {
+    let bytes = input.as_bytes();
+    fnv1a_continue_64(FNV_OFFSET_BASIS_64, bytes, clamp_len(bytes, limit), false)
+}
+
+/// Computes 32-bit fnv1a hash of the first limit bytes of a str.
+/// If limit exceeds str length, the whole str is hashed.
+#[inline(always)]
+pub const fn fnv1a_hash_str_32_limit(input: &str, limit: usize) -> u32 {
+    let bytes = input.as_bytes();
+    fnv1a_continue_32(FNV_OFFSET_BASIS_32, bytes, clamp_len(bytes, limit), false)
+}
+
+/// Computes 64-bit case-insensitive fnv1a hash of the first limit bytes of a str.
+/// Only ascii lowercase letters are folded, as with `fnv1a_hash_64_ascii_case_insensitive`.
+/// If limit exceeds str length, the whole str is hashed.
+#[inline(always)]
+pub const fn fnv1a_hash_str_case_64_limit(input: &str, limit: usize) -> u64 {
+    let bytes = input.as_bytes();
+    fnv1a_continue_letters_64(FNV_OFFSET_BASIS_64, bytes, clamp_len(bytes, limit), true)
+}
+
+/// Computes 32-bit case-insensitive fnv1a hash of the first limit bytes of a str.
+/// Only ascii lowercase letters are folded, as with `fnv1a_hash_32_ascii_case_insensitive`.
+/// If limit exceeds str length, the whole str is hashed.
+#[inline(always)]
+pub const fn fnv1a_hash_str_case_32_limit(input: &str, limit: usize) -> u32 {
+    let bytes = input.as_bytes();
+    fnv1a_continue_letters_32(FNV_OFFSET_BASIS_32, bytes, clamp_len(bytes, limit), true)
+}
+
+
+/// Returns the byte length of the first char_limit chars of a str.
+const fn char_limit_len(input: &str, char_limit: usize) -> usize {
+    let bytes = input.as_bytes();
+    let mut chars = 0;
+
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] & 0xC0 != 0x80 {
+            if chars == char_limit {
+                break;
+            }
+            chars += 1;
+        }
+        i += 1;
+    }
+    i
+}
+
+/// Computes 64-bit fnv1a hash of the first char_limit chars of a str.
+/// If char_limit exceeds the char count, the whole str is hashed.
+#[inline(always)]
+pub const fn fnv1a_hash_str_64_char_limit(input: &str, char_limit: usize) -> u64 {
+    let len = char_limit_len(input, char_limit);
+    fnv1a_continue_64(FNV_OFFSET_BASIS_64, input.as_bytes(), len, false)
+}
+
+/// Computes 32-bit fnv1a hash of the first char_limit chars of a str.
+/// If char_limit exceeds the char count, the whole str is hashed.
+#[inline(always)]
+pub const fn fnv1a_hash_str_32_char_limit(input: &str, char_limit: usize) -> u32 {
+    let len = char_limit_len(input, char_limit);
+    fnv1a_continue_32(FNV_OFFSET_BASIS_32, input.as_bytes(), len, false)
+}
+
+
+/// Computes 64-bits fnv1a hash and XORs higher and lower 32-bits.
+/// This results in a 32-bits hash value.
+/// If limit is zero or exceeds slice length, slice length is used instead.
+/// If fold_to_upper is set, only ascii lowercase letters are hashed as uppercase.
+#[inline(always)]
+pub const fn fnv1a_hash_64_xor_fold(bytes: &[u8], limit: Option<usize>, fold_to_upper: bool) -> u32 {
+    let hash = fnv1a_continue_letters_64(FNV_OFFSET_BASIS_64, bytes, effective_len(bytes, limit), fold_to_upper);
+    ((hash >> 32) as u32) ^ (hash as u32)
+}
+
+/// Computes 64-bits fnv1a hash and ANDs higher and lower 32-bits.
+/// This results in a 32-bits hash value.
+/// If limit is zero or exceeds slice length, slice length is used instead.
+/// If fold_to_upper is set, only ascii lowercase letters are hashed as uppercase.
+#[inline(always)]
+pub const fn fnv1a_hash_64_and_fold(bytes: &[u8], limit: Option<usize>, fold_to_upper: bool) -> u32 {
+    let hash = fnv1a_continue_letters_64(FNV_OFFSET_BASIS_64, bytes, effective_len(bytes, limit), fold_to_upper);
+    ((hash >> 32) as u32) & (hash as u32)
+}
+
+
+/// Types which can be hashed using fnv1a.
+/// Implementations should delegate to the const hashing functions of this crate.
+/// Trait methods can not be `const fn` on stable Rust, these are regular functions.
+///
+/// ```
+/// use const_fnv1a_hash::{fnv1a_hash_32_case_sensitive, fnv1a_hash_64_case_sensitive, ConstHash};
+///
+/// struct Id([u8; 4]);
+///
+/// impl ConstHash for Id {
+///     fn hash_64(&self) -> u64 {
+///         fnv1a_hash_64_case_sensitive(&self.0, None)
+///     }
+///
+///     fn hash_32(&self) -> u32 {
+///         fnv1a_hash_32_case_sensitive(&self.0, None)
+///     }
+/// }
+/// ```
+pub trait ConstHash {
+    /// Computes 64-bits fnv1a hash of the value.
+    fn hash_64(&self) -> u64;
+
+    /// Computes 32-bits fnv1a hash of the value.
+    fn hash_32(&self) -> u32;
+}
+
+impl ConstHash for str {
+    #[inline(always)]
+    fn hash_64(&self) -> u64 {
+        fnv1a_hash_str_64(self)
+    }
+
+    #[inline(always)]
+    fn hash_32(&self) -> u32 {
+        fnv1a_hash_str_32(self)
+    }
+}
+
+impl ConstHash for [u8] {
+    #[inline(always)]
+    fn hash_64(&self) -> u64 {
+        fnv1a_hash_64_case_sensitive(self, None)
+    }
+
+    #[inline(always)]
+    fn hash_32(&self) -> u32 {
+        fnv1a_hash_32_case_sensitive(self, None)
+    }
+}
+
+impl<T: ConstHash + ?Sized> ConstHash for &T {
+    #[inline(always)]
+    fn hash_64(&self) -> u64 {
+        (**self).hash_64()
+    }
+
+    #[inline(always)]
+    fn hash_32(&self) -> u32 {
+        (**self).hash_32()
+    }
+}
+
+impl ConstHash for bool {
+    #[inline(always)]
+    fn hash_64(&self) -> u64 {
+        fnv1a_hash_u8_64(*self as u8)
+    }
+
+    #[inline(always)]
+    fn hash_32(&self) -> u32 {
+        fnv1a_hash_u8_32(*self as u8)
+    }
+}
+
+impl ConstHash for char {
+    #[inline(always)]
+    fn hash_64(&self) -> u64 {
+        fnv1a_hash_char_64(*self)
+    }
+
+    #[inline(always)]
+    fn hash_32(&self) -> u32 {
+        fnv1a_hash_char_32(*self)
+    }
+}
+
+macro_rules! impl_const_hash_primitive {
+    ($($ty:ty => $hash_64:ident, $hash_32:ident;)*) => {
+        $(
+            impl ConstHash for $ty {
+                #[inline(always)]
+                fn hash_64(&self) -> u64 {
+                    $hash_64(*self)
+                }
+
+                #[inline(always)]
+                fn hash_32(&self) -> u32 {
+                    $hash_32(*self)
+                }
+            }
+        )*
+    };
+}
+
+impl_const_hash_primitive! {
+    u8 => fnv1a_hash_u8_64, fnv1a_hash_u8_32;
+    u16 => fnv1a_hash_u16_64, fnv1a_hash_u16_32;
+    u32 => fnv1a_hash_u32_64, fnv1a_hash_u32_32;
+    u64 => fnv1a_hash_u64_64, fnv1a_hash_u64_32;
+    u128 => fnv1a_hash_u128_64, fnv1a_hash_u128_32;
+    usize => fnv1a_hash_usize_64, fnv1a_hash_usize_32;
+    i8 => fnv1a_hash_i8_64, fnv1a_hash_i8_32;
+    i16 => fnv1a_hash_i16_64, fnv1a_hash_i16_32;
+    i32 => fnv1a_hash_i32_64, fnv1a_hash_i32_32;
+    i64 => fnv1a_hash_i64_64, fnv1a_hash_i64_32;
+    i128 => fnv1a_hash_i128_64, fnv1a_hash_i128_32;
+    isize => fnv1a_hash_isize_64, fnv1a_hash_isize_32;
+}
+
+/// Fixed-size map using fnv1a for slot selection and linear probing on collisions.
+/// Does not allocate, all N slots are stored inline.
+/// `new` and `get` are not const fns, since they hash and compare keys through the
+/// `ConstHash` and `PartialEq` trait methods, which can not be called in a const fn on stable.
+/// For a map of str keys built at compile time use `FnvMap`, usually through `const_map!`.
+#[derive(Clone, Debug)]
+pub struct StaticMap<K, V, const N: usize> {
+    slots: [Option<(K, V)>; N],
+}
+
+impl<K: ConstHash + PartialEq, V, const N: usize> StaticMap<K, V, N> {
+    /// Creates a map from the given entries. Later entries replace earlier ones with an equal key.
+    /// Panics if there are more entries than slots.
+    pub fn new<const M: usize>(entries: [(K, V); M]) -> Self {
+        assert!(M <= N, "StaticMap has fewer slots than entries");
+        let mut map = Self { slots: core::array::from_fn(|_| None) };
+        for (key, value) in entries {
+            let mut slot = map.first_slot(&key);
+            loop {
+                match &map.slots[slot] {
+                    Some((existing, _)) if *existing != key => slot = (slot + 1) % N,
+                    _ => break,
+                }
+            }
+            map.slots[slot] = Some((key, value));
+        }
+        map
+    }
+
+    /// Returns the value stored for key, if any.
+    pub fn get(&self, key: &K) -> Option<&V> {
+        if N == 0 {
+            return None;
+        }
+        let mut slot = self.first_slot(key);
+        for _ in 0..N {
+            match &self.slots[slot] {
+                Some((existing, value)) if existing == key => return Some(value),
+                Some(_) => slot = (slot + 1) % N,
+                None => return None,
+            }
+        }
+        None
+    }
+
+    #[inline(always)]
+    fn first_slot(&self, key: &K) -> usize {
+        (key.hash_64() % N as u64) as usize
+    }
+}
+
+
+/// Compares two byte slices for equality in a const context.
+const fn bytes_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Set of strs sorted by their 64-bit fnv1a hash, built at compile time.
+/// Lookups binary search the hash and then compare the str itself,
+/// so colliding strs are never reported as members.
+/// Usually created through the `const_str_set!` macro.
+#[derive(Clone, Copy, Debug)]
+pub struct ConstStrSet<const N: usize> {
+    entries: [(u64, &'static str); N],
+}
+
+impl<const N: usize> ConstStrSet<N> {
+    /// Creates a set from the given strs.
+    pub const fn new(strs: [&'static str; N]) -> Self {
+        let mut entries = [(0, ""); N];
+
+        let mut i = 0;
+        while i < N {
+            entries[i] = (fnv1a_hash_str_64(strs[i]), strs[i]);
+
+            let mut j = i;
+            while j > 0 && entries[j - 1].0 > entries[j].0 {
+                let tmp = entries[j];
+                entries[j] = entries[j - 1];
+                entries[j - 1] = tmp;
+                j -= 1;
+            }
+            i += 1;
+        }
+        Self { entries }
+    }
+
+    /// Returns whether the set contains the given str.
+    pub const fn contains(&self, input: &str) -> bool {
+        let hash = fnv1a_hash_str_64(input);
+
+        let mut low = 0;
+        let mut high = N;
+        while low < high {
+            let mid = low + (high - low) / 2;
+            if self.entries[mid].0 < hash {
+                low = mid + 1;
+            } else {
+                high = mid;
+            }
+        }
+
+        while low < N && self.entries[low].0 == hash {
+            if bytes_eq(self.entries[low].1.as_bytes(), input.as_bytes()) {
+                return true;
+            }
+            low += 1;
+        }
+        false
+    }
+}
+
+/// Declares a `ConstStrSet` constant built at compile time.
+///
+/// ```
+/// const_fnv1a_hash::const_str_set!(KEYWORDS = ["if", "else", "while", "for"]);
+///
+/// assert!(KEYWORDS.contains("while"));
+/// assert!(!KEYWORDS.contains("loop"));
+/// ```
+#[macro_export]
+macro_rules! const_str_set {
+    ($vis:vis $name:ident = [$($input:expr),* $(,)?]) => {
+        $vis const $name: $crate::ConstStrSet<{ <[&str]>::len(&[$($input),*]) }> =
+            $crate::ConstStrSet::new([$($input),*]);
+    };
+}
+
+
+/// Computes 64-bit fnv1a hash of a `CStr`, excluding the nul terminator.
+#[inline(always)]
+pub const fn fnv1a_hash_cstr_64(input: &core::ffi::CStr) -> u64 {
+    fnv1a_hash_64_case_sensitive(input.to_bytes(), None)
+}
+
+/// Computes 32-bit fnv1a hash of a `CStr`, excluding the nul terminator.
+#[inline(always)]
+pub const fn fnv1a_hash_cstr_32(input: &core::ffi::CStr) -> u32 {
+    fnv1a_hash_32_case_sensitive(input.to_bytes(), None)
+}
+
+/// Returns the position of the first nul byte, or slice length if there is none.
+const fn nul_position(bytes: &[u8]) -> usize {
+    let mut i = 0;
+    while i < bytes.len() && bytes[i] != 0 {
+        i += 1;
+    }
+    i
+}
+
+/// Computes 64-bits fnv1a hash of the bytes before the first nul byte.
+#[inline(always)]
+pub const fn fnv1a_hash_nul_terminated_64(bytes: &[u8]) -> u64 {
+    fnv1a_continue_64(FNV_OFFSET_BASIS_64, bytes, nul_position(bytes), false)
+}
+
+/// Computes 32-bits fnv1a hash of the bytes before the first nul byte.
+#[inline(always)]
+pub const fn fnv1a_hash_nul_terminated_32(bytes: &[u8]) -> u32 {
+    fnv1a_continue_32(FNV_OFFSET_BASIS_32, bytes, nul_position(bytes), false)
+}
+
+
+/// Combines two 64-bits hashes by continuing h1 over the little-endian bytes of h2.
+/// The result depends on the order of the arguments.
+#[inline(always)]
+pub const fn fnv1a_hash_combine_64(h1: u64, h2: u64) -> u64 {
+    let bytes = h2.to_le_bytes();
+    fnv1a_continue_64(h1, &bytes, bytes.len(), false)
+}
+
+/// Combines two 32-bits hashes by continuing h1 over the little-endian bytes of h2.
+/// The result depends on the order of the arguments.
+#[inline(always)]
+pub const fn fnv1a_hash_combine_32(h1: u32, h2: u32) -> u32 {
+    let bytes = h2.to_le_bytes();
+    fnv1a_continue_32(h1, &bytes, bytes.len(), false)
+}
+
+
+/// Rolling 32-bits fnv1a hasher over the last W bytes.
+/// fnv1a can not remove bytes from its state, so the window is rehashed on every push.
+#[derive(Clone, Copy, Debug)]
+pub struct FnvRollingHasher32<const W: usize> {
+    window: [u8; W],
+    pos: usize,
+    filled: usize,
+}
+
+impl<const W: usize> FnvRollingHasher32<W> {
+    /// Creates a rolling hasher with an empty window.
+    pub const fn new() -> Self {
+        Self { window: [0; W], pos: 0, filled: 0 }
+    }
+
+    /// Pushes a byte into the window, evicting the oldest one once the window is full.
+    /// Returns the hash of the bytes currently in the window, oldest first.
+    pub const fn push_byte(&mut self, byte: u8) -> u32 {
+        if W == 0 {
+            return FNV_OFFSET_BASIS_32;
+        }
+        self.window[self.pos] = byte;
+        self.pos = (self.pos + 1) % W;
+        if self.filled < W {
+            self.filled += 1;
+        }
+        self.hash()
+    }
+
+    /// Returns the hash of the bytes currently in the window, oldest first.
+    pub const fn hash(&self) -> u32 {
+        let mut hash = FNV_OFFSET_BASIS_32;
+
+        let mut i = 0;
+        while i < self.filled {
+            let index = (self.pos + W - self.filled + i) % W;
+            hash ^= self.window[index] as u32;
+            hash = hash.wrapping_mul(FNV_PRIME_32);
+            i += 1;
+        }
+        hash
+    }
+}
+
+impl<const W: usize> Default for FnvRollingHasher32<W> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+
+/// Computes 64-bit fnv1a hash of a u16 slice using the big-endian bytes of each value.
+pub const fn fnv1a_hash_u16_slice_be_64(values: &[u16]) -> u64 {
+    let mut hash = FNV_OFFSET_BASIS_64;
+
+    let mut i = 0;
+    while i < values.len() {
+        let bytes = values[i].to_be_bytes();
+        hash = fnv1a_continue_64(hash, &bytes, bytes.len(), false);
+        i += 1;
+    }
+    hash
+}
+
+/// Computes 64-bit fnv1a hash of a u32 slice using the big-endian bytes of each value.
+pub const fn fnv1a_hash_u32_slice_be_64(values: &[u32]) -> u64 {
+    let mut hash = FNV_OFFSET_BASIS_64;
+
+    let mut i = 0;
+    while i < values.len() {
+        let bytes = values[i].to_be_bytes();
+        hash = fnv1a_continue_64(hash, &bytes, bytes.len(), false);
+        i += 1;
+    }
+    hash
+}
+
+/// Computes 64-bit fnv1a hash of a u64 slice using the big-endian bytes of each value.
+pub const fn fnv1a_hash_u64_slice_be_64(values: &[u64]) -> u64 {
+    let mut hash = FNV_OFFSET_BASIS_64;
+
+    let mut i = 0;
+    while i < values.len() {
+        let bytes = values[i].to_be_bytes();
+        hash = fnv1a_continue_64(hash, &bytes, bytes.len(), false);
+        i += 1;
+    }
+    hash
+}
+
+
+/// Returns whether the 64-bits fnv1a hash of the given slice equals expected.
+/// The comparison is a single XOR of the full words, without early exit.
+#[inline(always)]
+pub const fn fnv1a_hash_verify_64(bytes: &[u8], expected: u64) -> bool {
+    (fnv1a_hash_64_case_sensitive(bytes, None) ^ expected) == 0
+}
+
+/// Returns whether the 32-bits fnv1a hash of the given slice equals expected.
+/// The comparison is a single XOR of the full words, without early exit.
+#[inline(always)]
+pub const fn fnv1a_hash_verify_32(bytes: &[u8], expected: u32) -> bool {
+    (fnv1a_hash_32_case_sensitive(bytes, None) ^ expected) == 0
+}
+
+
+/// Domain byte fed before the value of `NonZero*` types.
+const NONZERO_DOMAIN: u8 = 0x01;
+
+/// Computes 64-bit fnv1a hash of a NonZeroU32 using its little-endian bytes.
+/// A domain byte is hashed first, so the result differs from `fnv1a_hash_u32_64`.
+#[inline(always)]
+pub const fn fnv1a_hash_nonzero_u32_64(value: core::num::NonZeroU32) -> u64 {
+    let bytes = value.get().to_le_bytes();
+    let hash = fnv1a_continue_64(FNV_OFFSET_BASIS_64, &[NONZERO_DOMAIN], 1, false);
+    fnv1a_continue_64(hash, &bytes, bytes.len(), false)
+}
+
+/// Computes 64-bit fnv1a hash of a NonZeroU64 using its little-endian bytes.
+/// A domain byte is hashed first, so the result differs from `fnv1a_hash_u64_64`.
+#[inline(always)]
+pub const fn fnv1a_hash_nonzero_u64_64(value: core::num::NonZeroU64) -> u64 {
+    let bytes = value.get().to_le_bytes();
+    let hash = fnv1a_continue_64(FNV_OFFSET_BASIS_64, &[NONZERO_DOMAIN], 1, false);
+    fnv1a_continue_64(hash, &bytes, bytes.len(), false)
+}
+
+/// Computes 64-bit fnv1a hash of a NonZeroUsize using its little-endian bytes.
+/// A domain byte is hashed first, so the result differs from `fnv1a_hash_usize_64`.
+#[inline(always)]
+pub const fn fnv1a_hash_nonzero_usize_64(value: core::num::NonZeroUsize) -> u64 {
+    let bytes = value.get().to_le_bytes();
+    let hash = fnv1a_continue_64(FNV_OFFSET_BASIS_64, &[NONZERO_DOMAIN], 1, false);
+    fnv1a_continue_64(hash, &bytes, bytes.len(), false)
+}
+
+
+/// Computes 64-bit fnv1a hash of a str split on delimiter.
+/// Each segment is prefixed by its length as 8 little-endian bytes,
+/// so moving bytes or delimiters between segments changes the hash.
+pub const fn fnv1a_hash_str_split_64(input: &str, delimiter: u8) -> u64 {
+    let bytes = input.as_bytes();
+    let mut hash = FNV_OFFSET_BASIS_64;
+
+    let mut start = 0;
+    let mut i = 0;
+    while i <= bytes.len() {
+        if i == bytes.len() || bytes[i] == delimiter {
+            let len = ((i - start) as u64).to_le_bytes();
+            hash = fnv1a_continue_64(hash, &len, len.len(), false);
+            hash = fnv1a_continue_range_64(hash, bytes, start, i, false);
+            start = i + 1;
+        }
+        i += 1;
+    }
+    hash
+}
+
+
+/// Computes 64-bits fnv1a hash and returns its higher 32-bits.
+#[inline(always)]
+pub const fn fnv1a_hash_64_high32(bytes: &[u8]) -> u32 {
+    (fnv1a_hash_64_case_sensitive(bytes, None) >> 32) as u32
+}
+
+/// Computes 64-bits fnv1a hash and returns its lower 32-bits.
+#[inline(always)]
+pub const fn fnv1a_hash_64_low32(bytes: &[u8]) -> u32 {
+    fnv1a_hash_64_case_sensitive(bytes, None) as u32
+}
+
+/// Computes 64-bits fnv1a hash and XORs higher and lower 32-bits.
+#[inline(always)]
+pub const fn fnv1a_hash_64_to_u32(bytes: &[u8]) -> u32 {
+    fnv1a_hash_64_xor_fold(bytes, None, false)
+}
+
+
+/// Upper bound on the seeds tried per bucket when building a `PerfectHashTable`.
+const PERFECT_HASH_MAX_SEED: u64 = 1 << 16;
+
+/// Maps a 64-bits hash onto [0, n) using its high bits, the low bits of fnv1a mix poorly.
+#[inline(always)]
+const fn reduce_64(hash: u64, n: usize) -> usize {
+    ((hash as u128 * n as u128) >> 64) as usize
+}
+
+/// Returns the slot of key in a perfect hash table of n slots using seed.
+#[inline(always)]
+const fn perfect_hash_slot(key: &str, seed: u64, n: usize) -> usize {
+    let bytes = key.as_bytes();
+    reduce_64(fnv1a_continue_64(FNV_OFFSET_BASIS_64 ^ seed, bytes, bytes.len(), false), n)
+}
+
+/// Perfect hash table mapping N strs onto N slots without collisions.
+/// Keys are assigned to buckets by their 64-bit fnv1a hash, each bucket stores
+/// the seed which places its keys onto free slots.
+/// Built at compile time through `build_perfect_hash`.
+#[derive(Clone, Copy, Debug)]
+pub struct PerfectHashTable<const N: usize> {
+    seeds: [u64; N],
+    keys: [&'static str; N],
+    indices: [usize; N],
+}
+
+impl<const N: usize> PerfectHashTable<N> {
+    /// Returns the position of key in the array the table was built from, if present.
+    pub const fn get(&self, key: &str) -> Option<usize> {
+        if N == 0 {
+            return None;
+        }
+        let bucket = reduce_64(fnv1a_hash_str_64(key), N);
+        let slot = perfect_hash_slot(key, self.seeds[bucket], N);
+        if bytes_eq(self.keys[slot].as_bytes(), key.as_bytes()) {
+            Some(self.indices[slot])
+        } else {
+            None
+        }
+    }
+}
+
+/// Builds a perfect hash table for the given keys.
+/// Buckets are placed largest first, searching the first seed for which all keys
+/// of the bucket land on distinct free slots.
+/// Panics if keys contains duplicates or no seed is found for a bucket.
+pub const fn build_perfect_hash<const N: usize>(keys: &[&'static str; N]) -> PerfectHashTable<N> {
+    let mut table = PerfectHashTable { seeds: [0; N], keys: [""; N], indices: [0; N] };
+    let mut buckets = [0; N];
+    let mut sizes = [0; N];
+
+    let mut i = 0;
+    while i < N {
+        let mut j = 0;
+        while j < i {
+            assert!(!bytes_eq(keys[i].as_bytes(), keys[j].as_bytes()), "duplicate key in perfect hash table");
+            j += 1;
+        }
+        buckets[i] = reduce_64(fnv1a_hash_str_64(keys[i]), N);
+        sizes[buckets[i]] += 1;
+        i += 1;
+    }
+
+    let mut occupied = [false; N];
+    let mut placed = [false; N];
+    let mut remaining = N;
+    while remaining > 0 {
+        let mut bucket = 0;
+        let mut b = 0;
+        while b < N {
+            if !placed[b] && (placed[bucket] || sizes[b] > sizes[bucket]) {
+                bucket = b;
+            }
+            b += 1;
+        }
+        placed[bucket] = true;
+        remaining -= 1;
+        if sizes[bucket] == 0 {
+            break;
+        }
+
+        let mut seed = 0;
+        loop {
+            assert!(seed < PERFECT_HASH_MAX_SEED, "no perfect hash seed found");
+            let mut trial = occupied;
+            let mut fits = true;
+
+            let mut k = 0;
+            while k < N {
+                if buckets[k] == bucket {
+                    let slot = perfect_hash_slot(keys[k], seed, N);
+                    if trial[slot] {
+                        fits = false;
+                        break;
+                    }
+                    trial[slot] = true;
+                }
+                k += 1;
+            }
+
+            if fits {
+                let mut k = 0;
+                while k < N {
+                    if buckets[k] == bucket {
+                        let slot = perfect_hash_slot(keys[k], seed, N);
+                        table.keys[slot] = keys[k];
+                        table.indices[slot] = k;
+                    }
+                    k += 1;
+                }
+                table.seeds[bucket] = seed;
+                occupied = trial;
+                break;
+            }
+            seed += 1;
+        }
+    }
+    table
+}
+
+
+/// Domain byte hashed for `None` values.
+const NONE_DOMAIN: u8 = 0xFF;
+/// Domain byte hashed before the bytes of `Some` values.
+const SOME_DOMAIN: u8 = 0x00;
+
+/// Computes 64-bits fnv1a hash of an optional slice.
+/// A domain byte is hashed first, so `None` and `Some(b"")` do not collide.
+pub const fn fnv1a_hash_option_64(input: Option<&[u8]>) -> u64 {
+    match input {
+        Some(bytes) => {
+            let hash = fnv1a_continue_64(FNV_OFFSET_BASIS_64, &[SOME_DOMAIN], 1, false);
+            fnv1a_continue_64(hash, bytes, bytes.len(), false)
+        }
+        None => fnv1a_continue_64(FNV_OFFSET_BASIS_64, &[NONE_DOMAIN], 1, false),
+    }
+}
+
+/// Computes 64-bit fnv1a hash of an optional str.
+/// A domain byte is hashed first, so `None` and `Some("")` do not collide.
+#[inline(always)]
+pub const fn fnv1a_hash_option_str_64(input: Option<&str>) -> u64 {
+    match input {
+        Some(input) => fnv1a_hash_option_64(Some(input.as_bytes())),
+        None => fnv1a_hash_option_64(None),
+    }
+}
+
+
+/// Computes 64-bits fnv1a hash of the bytes from start up-to end without re-slicing.
+/// End is clamped to slice length, an empty range hashes nothing.
+#[inline(always)]
+pub const fn fnv1a_hash_64_from_offset(bytes: &[u8], start: usize, end: usize) -> u64 {
+    let end = clamp_len(bytes, end);
+    if start >= end {
+        return FNV_OFFSET_BASIS_64;
+    }
+    fnv1a_continue_range_64(FNV_OFFSET_BASIS_64, bytes, start, end, false)
+}
+
+/// Computes 32-bits fnv1a hash of the bytes from start up-to end without re-slicing.
+/// End is clamped to slice length, an empty range hashes nothing.
+#[inline(always)]
+pub const fn fnv1a_hash_32_from_offset(bytes: &[u8], start: usize, end: usize) -> u32 {
+    let end = clamp_len(bytes, end);
+    if start >= end {
+        return FNV_OFFSET_BASIS_32;
+    }
+    fnv1a_continue_range_32(FNV_OFFSET_BASIS_32, bytes, start, end, false)
+}
+
+
+/// Computes 64-bits fnv1a hashes of the even-indexed and odd-indexed bytes separately.
+/// Returns the hash of the even bytes first.
+#[inline(always)]
+pub const fn fnv1a_hash_64_alternating(bytes: &[u8]) -> (u64, u64) {
+    (fnv1a_hash_64_skip(bytes, 2, 0), fnv1a_hash_64_skip(bytes, 2, 1))
+}
+
+/// Computes 64-bits fnv1a hashes of the even-indexed and odd-indexed bytes and XORs them.
+#[inline(always)]
+pub const fn fnv1a_hash_64_alternating_combined(bytes: &[u8]) -> u64 {
+    let (even, odd) = fnv1a_hash_64_alternating(bytes);
+    even ^ odd
+}
+
+
+/// Minimum brightness bits set on every channel of `fnv1a_hash_str_to_rgb`.
+const RGB_BRIGHTNESS_MASK: u8 = 0x40;
+
+/// Computes 32-bit fnv1a hash from a str and maps it onto an RGB color.
+/// The lower three little-endian bytes of the hash form the channels,
+/// which are kept from being too dark.
+#[inline(always)]
+pub const fn fnv1a_hash_str_to_rgb(input: &str) -> (u8, u8, u8) {
+    let bytes = fnv1a_hash_str_32(input).to_le_bytes();
+    (
+        bytes[0] | RGB_BRIGHTNESS_MASK,
+        bytes[1] | RGB_BRIGHTNESS_MASK,
+        bytes[2] | RGB_BRIGHTNESS_MASK,
+    )
+}
+
+
+/// Computes 64-bits fnv1a hash of len bytes starting at ptr.
+///
+/// # Safety
+///
+/// ptr must be non-null and valid for reads of len bytes, and the memory
+/// must not be mutated for the duration of the call.
+/// See `core::slice::from_raw_parts` for the full contract.
+#[inline(always)]
+pub const unsafe fn fnv1a_hash_64_bytes_at(ptr: *const u8, len: usize) -> u64 {
+    fnv1a_hash_64_case_sensitive(core::slice::from_raw_parts(ptr, len), None)
+}
+
+
+/// Compile-time interning table assigning each str its position as id.
+/// Lookups compare the 64-bit fnv1a hash first and then the str itself.
+/// Usually created through the `const_intern!` macro.
+#[derive(Clone, Copy, Debug)]
+pub struct ConstInterner<const N: usize> {
+    strs: [&'static str; N],
+    hashes: [u64; N],
+}
+
+impl<const N: usize> ConstInterner<N> {
+    /// Creates an interning table for the given strs.
+    pub const fn new(strs: [&'static str; N]) -> Self {
+        let mut hashes = [0; N];
+
+        let mut i = 0;
+        while i < N {
+            hashes[i] = fnv1a_hash_str_64(strs[i]);
+            i += 1;
+        }
+        Self { strs, hashes }
+    }
+
+    /// Returns the 64-bit fnv1a hashes of the interned strs, ordered by id.
+    pub const fn hashes(&self) -> &[u64; N] {
+        &self.hashes
+    }
+
+    /// Returns the id of the given str, if it is interned.
+    pub const fn intern(&self, input: &str) -> Option<u32> {
+        let hash = fnv1a_hash_str_64(input);
+
+        let mut i = 0;
+        while i < N {
+            if self.hashes[i] == hash && bytes_eq(self.strs[i].as_bytes(), input.as_bytes()) {
+                return Some(i as u32);
+            }
+            i += 1;
+        }
+        None
+    }
+
+    /// Returns the str interned under id.
+    /// Panics if id is out of range.
+    pub const fn unintern(&self, id: u32) -> &'static str {
+        self.strs[id as usize]
+    }
+}
+
+/// Declares a `ConstInterner` constant built at compile time.
+///
+/// ```
+/// const_fnv1a_hash::const_intern!(STRINGS = ["alpha", "beta", "gamma"]);
+///
+/// assert_eq!(STRINGS.intern("beta"), Some(1));
+/// assert_eq!(STRINGS.unintern(1), "beta");
+/// ```
+#[macro_export]
+macro_rules! const_intern {
+    ($vis:vis $name:ident = [$($input:expr),* $(,)?]) => {
+        $vis const $name: $crate::ConstInterner<{ <[&str]>::len(&[$($input),*]) }> =
+            $crate::ConstInterner::new([$($input),*]);
+    };
+}
+
+
+/// Computes 32-bits fnv1a hash of salt followed by bytes, as if they were concatenated.
+/// Unlike the seeded variant, the standard offset basis is kept and the salt is fed as input.
+#[inline(always)]
+pub const fn fnv1a_hash_32_with_salt(bytes: &[u8], salt: &[u8]) -> u32 {
+    let hash = fnv1a_continue_32(FNV_OFFSET_BASIS_32, salt, salt.len(), false);
+    fnv1a_continue_32(hash, bytes, bytes.len(), false)
+}
+
+
+/// Computes 64-bits fnv1a hash of bytes taken in ascending order, so permutations hash equally.
+/// Bytes are counted instead of sorted, so any length is handled without a buffer.
+pub const fn fnv1a_hash_64_sorted_bytes(bytes: &[u8]) -> u64 {
+    let mut counts = [0usize; 256];
+
+    let mut i = 0;
+    while i < bytes.len() {
+        counts[bytes[i] as usize] += 1;
+        i += 1;
+    }
+
+    let mut hash = FNV_OFFSET_BASIS_64;
+
+    let mut byte = 0;
+    while byte < counts.len() {
+        let mut count = counts[byte];
+        while count > 0 {
+            hash ^= byte as u64;
+            hash = hash.wrapping_mul(FNV_PRIME_64);
+            count -= 1;
+        }
+        byte += 1;
+    }
+    hash
+}
+
+
+/// Computes 64-bits fnv1a hash of str after Unicode NFC normalization.
+/// ASCII input is already normalized and is hashed directly.
+#[cfg(feature = "unicode")]
+pub fn fnv1a_hash_str_64_normalized(input: &str) -> u64 {
+    use unicode_normalization::UnicodeNormalization;
+
+    if input.is_ascii() {
+        return fnv1a_hash_str_64(input);
+    }
+
+    input.nfc().fold(FNV_OFFSET_BASIS_64, |hash, c| {
+        let (buf, len) = encode_utf8(c);
+        fnv1a_continue_64(hash, &buf, len, false)
+    })
+}
+
+
+/// Computes 64-bits fnv1a hash that is never zero, for APIs using zero as a sentinel.
+/// A zero hash has its lowest bit set; every other hash is returned unchanged.
+#[inline(always)]
+pub const fn fnv1a_hash_64_nonzero(bytes: &[u8]) -> core::num::NonZeroU64 {
+    let hash = fnv1a_continue_64(FNV_OFFSET_BASIS_64, bytes, bytes.len(), false);
+    match core::num::NonZeroU64::new(hash | (hash == 0) as u64) {
+        Some(hash) => hash,
+        None => unreachable!(),
+    }
+}
+
+/// Computes 32-bits fnv1a hash that is never zero, for APIs using zero as a sentinel.
+/// A zero hash has its lowest bit set; every other hash is returned unchanged.
+#[inline(always)]
+pub const fn fnv1a_hash_32_nonzero(bytes: &[u8]) -> core::num::NonZeroU32 {
+    let hash = fnv1a_continue_32(FNV_OFFSET_BASIS_32, bytes, bytes.len(), false);
+    match core::num::NonZeroU32::new(hash | (hash == 0) as u32) {
+        Some(hash) => hash,
+        None => unreachable!(),
+    }
+}
+
+
+/// Computes 64-bits fnv1a hash mapped to a uniformly distributed f64 in [0.0, 1.0).
+/// The top 53 bits of the hash are used, which is the full precision of f64.
+#[inline(always)]
+pub const fn fnv1a_hash_64_as_f64(bytes: &[u8]) -> f64 {
+    let hash = fnv1a_continue_64(FNV_OFFSET_BASIS_64, bytes, bytes.len(), false);
+    (hash >> 11) as f64 * (1.0 / (1u64 << 53) as f64)
+}
+
+
+/// Builds the table of 32-bits fnv1a hashes for every single byte value.
+const fn build_table_32() -> [u32; 256] {
+    let mut table = [0; 256];
+
+    let mut i = 0;
+    while i < table.len() {
+        table[i] = fnv1a_continue_32(FNV_OFFSET_BASIS_32, &[i as u8], 1, false);
+        i += 1;
+    }
+    table
+}
+
+/// Builds the table of 64-bits fnv1a hashes for every single byte value.
+const fn build_table_64() -> [u64; 256] {
+    let mut table = [0; 256];
+
+    let mut i = 0;
+    while i < table.len() {
+        table[i] = fnv1a_continue_64(FNV_OFFSET_BASIS_64, &[i as u8], 1, false);
+        i += 1;
+    }
+    table
+}
+
+/// 32-bits fnv1a hash of each single byte, indexed by byte value.
+pub const FNV1A_BYTE_TABLE_32: [u32; 256] = build_table_32();
+
+/// 64-bits fnv1a hash of each single byte, indexed by byte value.
+pub const FNV1A_BYTE_TABLE_64: [u64; 256] = build_table_64();
+
+
+/// Computes 64-bits fnv1a hash of parts, each prefixed by its length as 8 little-endian bytes.
+/// Unlike `fnv1a_hash_64_chain`, moving bytes between parts changes the hash.
+pub const fn fnv1a_hash_64_length_separated(parts: &[&[u8]]) -> u64 {
+    let mut hash = FNV_OFFSET_BASIS_64;
+
+    let mut i = 0;
+    while i < parts.len() {
+        let len = (parts[i].len() as u64).to_le_bytes();
+        hash = fnv1a_continue_64(hash, &len, len.len(), false);
+        hash = fnv1a_continue_64(hash, parts[i], parts[i].len(), false);
+        i += 1;
+    }
+    hash
+}
+
+
+/// Maps a str to the value of the arm with the equal str literal, or the `_` arm otherwise.
+/// The input is hashed once and compared against the compile-time hash of each arm,
+/// and a matching hash is confirmed by comparing the strs, so colliding arms are handled.
+///
+/// ```
+/// # #[derive(Debug, PartialEq)]
+/// enum Method { Get, Post, Unknown }
+///
+/// let method = const_fnv1a_hash::fnv1a_dispatch!("post" => {
+///     "get" => Method::Get,
+///     "post" => Method::Post,
+///     _ => Method::Unknown,
+/// });
+/// assert_eq!(method, Method::Post);
+/// ```
+#[macro_export]
+macro_rules! fnv1a_dispatch {
+    ($input:expr => { $($key:literal => $value:expr,)* _ => $default:expr $(,)? }) => {{
+        let input: &str = $input;
+        let hash = $crate::fnv1a_hash_str_32(input);
+        $(
+            if hash == { const HASH: u32 = $crate::fnv1a_hash_str_32($key); HASH } && input == $key {
+                $value
+            } else
+        )* {
+            $default
+        }
+    }};
+}
+
+
+/// Computes 64-bits fnv1a hash and returns only the bits set in mask.
+#[inline(always)]
+pub const fn fnv1a_hash_64_mask(bytes: &[u8], mask: u64) -> u64 {
+    fnv1a_continue_64(FNV_OFFSET_BASIS_64, bytes, bytes.len(), false) & mask
+}
+
+/// Computes 64-bits fnv1a hash and returns its lowest bit_count bits.
+/// Panics in debug builds if bit_count exceeds 64; release builds return the full hash.
+#[inline(always)]
+pub const fn fnv1a_hash_64_bits(bytes: &[u8], bit_count: u32) -> u64 {
+    debug_assert!(bit_count <= 64);
+
+    let hash = fnv1a_continue_64(FNV_OFFSET_BASIS_64, bytes, bytes.len(), false);
+    if bit_count >= 64 {
+        hash
+    } else {
+        hash & ((1 << bit_count) - 1)
+    }
+}
+
+
+/// Computes 64-bits fnv1a hash of str with ASCII uppercase letters folded to lowercase.
+/// All other bytes are hashed unchanged.
+pub const fn fnv1a_hash_str_64_ascii_lower(input: &str) -> u64 {
+    let bytes = input.as_bytes();
+    let mut hash = FNV_OFFSET_BASIS_64;
+
+    let mut i = 0;
+    while i < bytes.len() {
+        hash ^= bytes[i].to_ascii_lowercase() as u64;
+        hash = hash.wrapping_mul(FNV_PRIME_64);
+        i += 1;
+    }
+    hash
+}
+
+/// Computes 32-bits fnv1a hash of str with ASCII uppercase letters folded to lowercase.
+/// All other bytes are hashed unchanged.
+pub const fn fnv1a_hash_str_32_ascii_lower(input: &str) -> u32 {
+    let bytes = input.as_bytes();
+    let mut hash = FNV_OFFSET_BASIS_32;
+
+    let mut i = 0;
+    while i < bytes.len() {
+        hash ^= bytes[i].to_ascii_lowercase() as u32;
+        hash = hash.wrapping_mul(FNV_PRIME_32);
+        i += 1;
+    }
+    hash
+}
+
+
+/// Iterator over 64-bits fnv1a hashes of fixed-size chunks, see `fnv1a_hash_64_chunks`.
+#[derive(Clone, Debug)]
+pub struct Fnv1aChunkHashes64<'a> {
+    chunks: core::slice::Chunks<'a, u8>,
+}
+
+impl Iterator for Fnv1aChunkHashes64<'_> {
+    type Item = u64;
+
+    #[inline]
+    fn next(&mut self) -> Option<u64> {
+        self.chunks.next().map(|chunk| fnv1a_continue_64(FNV_OFFSET_BASIS_64, chunk, chunk.len(), false))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.chunks.size_hint()
+    }
+}
+
+impl ExactSizeIterator for Fnv1aChunkHashes64<'_> {}
+
+/// Returns an iterator over the 64-bits fnv1a hash of each chunk_size bytes chunk.
+/// The last chunk is shorter if the slice length is not a multiple of chunk_size.
+/// Panics if chunk_size is zero.
+pub fn fnv1a_hash_64_chunks(bytes: &[u8], chunk_size: usize) -> Fnv1aChunkHashes64<'_> {
+    assert!(chunk_size != 0, "chunk_size must be non-zero");
+    Fnv1aChunkHashes64 { chunks: bytes.chunks(chunk_size) }
+}
+
+
+/// Returns the initial 32-bits fnv1a state, the offset basis.
+#[inline(always)]
+pub const fn fnv1a_start_32() -> u32 {
+    FNV_OFFSET_BASIS_32
+}
+
+/// Returns the initial 64-bits fnv1a state, the offset basis.
+#[inline(always)]
+pub const fn fnv1a_start_64() -> u64 {
+    FNV_OFFSET_BASIS_64
+}
+
+/// Applies a single 32-bits fnv1a step for one byte: XOR, then multiply by the prime.
+#[inline(always)]
+pub const fn fnv1a_step_32(state: u32, byte: u8) -> u32 {
+    (state ^ byte as u32).wrapping_mul(FNV_PRIME_32)
+}
+
+/// Applies a single 64-bits fnv1a step for one byte: XOR, then multiply by the prime.
+#[inline(always)]
+pub const fn fnv1a_step_64(state: u64, byte: u8) -> u64 {
+    (state ^ byte as u64).wrapping_mul(FNV_PRIME_64)
+}
+
+/// Computes 32-bits fnv1a hash of two bytes without forming a slice.
+#[inline(always)]
+pub const fn fnv1a_hash_pack2_32(b0: u8, b1: u8) -> u32 {
+    fnv1a_step_32(fnv1a_step_32(FNV_OFFSET_BASIS_32, b0), b1)
+}
+
+/// Computes 32-bits fnv1a hash of four bytes without forming a slice.
+#[inline(always)]
+pub const fn fnv1a_hash_pack4_32(b0: u8, b1: u8, b2: u8, b3: u8) -> u32 {
+    let hash = fnv1a_hash_pack2_32(b0, b1);
+    fnv1a_step_32(fnv1a_step_32(hash, b2), b3)
+}
+
+/// Computes 32-bits fnv1a hash of eight bytes without forming a slice.
+#[inline(always)]
+#[allow(clippy::too_many_arguments)]
+pub const fn fnv1a_hash_pack8_32(b0: u8, b1: u8, b2: u8, b3: u8, b4: u8, b5: u8, b6: u8, b7: u8) -> u32 {
+    let mut hash = fnv1a_hash_pack4_32(b0, b1, b2, b3);
+    hash = fnv1a_step_32(fnv1a_step_32(hash, b4), b5);
+    fnv1a_step_32(fnv1a_step_32(hash, b6), b7)
+}
+
+
+/// Computes 64-bits fnv1a hash of the little-endian bytes of nonce.
+/// Provides a family of deterministic hash outputs without any input data.
+#[inline(always)]
+pub const fn fnv1a_hash_64_with_nonce(nonce: u64) -> u64 {
+    fnv1a_hash_u64_64(nonce)
+}
+
+
+/// Computes 32-bits fnv1a hash of an ascii-only str.
+/// Panics if the input contains a non-ascii byte, which fails compilation in const contexts.
+///
+/// ```compile_fail
+/// const HASH: u32 = const_fnv1a_hash::fnv1a_hash_str_32_ascii_only("caf\u{e9}");
+/// ```
+pub const fn fnv1a_hash_str_32_ascii_only(input: &str) -> u32 {
+    match fnv1a_hash_str_32_try_ascii(input) {
+        Some(hash) => hash,
+        None => panic!("non-ASCII byte in input"),
+    }
+}
+
+/// Computes 32-bits fnv1a hash of an ascii-only str.
+/// Returns None if the input contains a non-ascii byte.
+pub const fn fnv1a_hash_str_32_try_ascii(input: &str) -> Option<u32> {
+    if !input.is_ascii() {
+        return None;
+    }
+    Some(fnv1a_hash_str_32(input))
+}
+
+
+/// Toy XOR keystream generator driven by the 64-bits fnv1a state.
+///
+/// **This is not a cipher.** The keystream is trivially predictable from a few known bytes
+/// and must never be used to protect data; it exists as a teaching example only.
+#[derive(Clone, Copy, Debug)]
+pub struct FnvKeystream {
+    state: u64,
+}
+
+impl FnvKeystream {
+    /// Creates a keystream starting from the 64-bits fnv1a hash of key.
+    pub const fn new(key: &[u8]) -> Self {
+        Self { state: fnv1a_continue_64(FNV_OFFSET_BASIS_64, key, key.len(), false) }
+    }
+
+    /// Returns the high byte of the state and feeds it back in as the next fnv1a input byte.
+    pub const fn next_byte(&mut self) -> u8 {
+        let byte = (self.state >> 56) as u8;
+        self.state = fnv1a_step_64(self.state, byte);
+        byte
+    }
+
+    /// XORs data with the keystream derived from key.
+    #[cfg(feature = "alloc")]
+    pub fn encrypt(key: &[u8], data: &[u8]) -> alloc::vec::Vec<u8> {
+        let mut keystream = Self::new(key);
+        data.iter().map(|byte| byte ^ keystream.next_byte()).collect()
+    }
+
+    /// Reverses `encrypt`, which is the same XOR with the same keystream.
+    #[cfg(feature = "alloc")]
+    pub fn decrypt(key: &[u8], data: &[u8]) -> alloc::vec::Vec<u8> {
+        Self::encrypt(key, data)
+    }
+}
+
+
+/// Computes an order-independent 64-bits fnv1a hash of the ascii whitespace delimited words of str.
+/// Word hashes are XOR-combined and the result is mixed by hashing its little-endian bytes.
+/// Since XOR cancels pairs, a word repeated an even number of times does not contribute.
+/// Input without words returns the offset basis.
+pub fn fnv1a_hash_str_64_words(input: &str) -> u64 {
+    let mut words = input.split_ascii_whitespace().peekable();
+    if words.peek().is_none() {
+        return FNV_OFFSET_BASIS_64;
+    }
+    fnv1a_hash_u64_64(words.fold(0, |combined, word| combined ^ fnv1a_hash_str_64(word)))
+}
+
+
+/// Computes 64-bits fnv1a hash as the exact ratio (hash, u64::MAX).
+/// The ratio lies in [0, 1], reaching 1 only for a hash of u64::MAX.
+#[inline(always)]
+pub const fn fnv1a_hash_64_ratio(bytes: &[u8]) -> (u64, u64) {
+    (fnv1a_continue_64(FNV_OFFSET_BASIS_64, bytes, bytes.len(), false), u64::MAX)
+}
+
+
+/// Computes 64-bit fnv1a hash of an IPv4 address using its 4 octets.
+#[inline(always)]
+pub const fn fnv1a_hash_ipv4_64(addr: core::net::Ipv4Addr) -> u64 {
+    let octets = addr.octets();
+    fnv1a_continue_64(FNV_OFFSET_BASIS_64, &octets, octets.len(), false)
+}
+
+/// Computes 64-bit fnv1a hash of an IPv6 address using its 16 octets.
+#[inline(always)]
+pub const fn fnv1a_hash_ipv6_64(addr: core::net::Ipv6Addr) -> u64 {
+    let octets = addr.octets();
+    fnv1a_continue_64(FNV_OFFSET_BASIS_64, &octets, octets.len(), false)
+}
+
+
+/// Iterator over 32-bits fnv1a hashes of each prefix, see `fnv1a_hash_str_prefixes_32`.
+#[derive(Clone, Debug)]
+pub struct Fnv1aPrefixHashes32<'a> {
+    bytes: core::slice::Iter<'a, u8>,
+    hash: u32,
+}
+
+impl Iterator for Fnv1aPrefixHashes32<'_> {
+    type Item = u32;
+
+    #[inline]
+    fn next(&mut self) -> Option<u32> {
+        let byte = *self.bytes.next()?;
+        self.hash = fnv1a_step_32(self.hash, byte);
+        Some(self.hash)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.bytes.size_hint()
+    }
+}
+
+impl ExactSizeIterator for Fnv1aPrefixHashes32<'_> {}
+
+/// Returns an iterator over the 32-bits fnv1a hash of every non-empty byte prefix of str.
+/// The state is carried between prefixes, so all hashes take linear time in total.
+/// Prefixes end on byte positions and need not be char boundaries.
+pub fn fnv1a_hash_str_prefixes_32(input: &str) -> Fnv1aPrefixHashes32<'_> {
+    Fnv1aPrefixHashes32 { bytes: input.as_bytes().iter(), hash: FNV_OFFSET_BASIS_32 }
+}
+
+
+/// Computes the 64-bits fnv1a hash of every input into the matching outputs slot.
+/// Panics if outputs is shorter than inputs; extra outputs are left untouched.
+pub fn fnv1a_hash_64_batch(inputs: &[&[u8]], outputs: &mut [u64]) {
+    assert!(outputs.len() >= inputs.len(), "outputs is shorter than inputs");
+
+    for (output, input) in outputs.iter_mut().zip(inputs) {
+        *output = fnv1a_continue_64(FNV_OFFSET_BASIS_64, input, input.len(), false);
+    }
+}
+
+
+/// Computes 64-bits fnv1a hash of the bytes yielded by iter, without collecting them.
+/// If fold_to_upper is set, ascii lowercase letters are hashed as uppercase and all other bytes unchanged.
+pub fn fnv1a_hash_64_from_iter<I: IntoIterator<Item = u8>>(iter: I, fold_to_upper: bool) -> u64 {
+    iter.into_iter().fold(FNV_OFFSET_BASIS_64, |hash, byte| {
+        (hash ^ fold_ascii_letter(byte, fold_to_upper) as u64).wrapping_mul(FNV_PRIME_64)
+    })
+}
+
+
+/// Computes 32-bits fnv1a hash of bytes read as big-endian 32-bit words.
+/// A trailing partial word is zero-padded on the right, so the result equals
+/// hashing the bytes in order followed by up-to three zero bytes.
+pub const fn fnv1a_hash_32_be_words(bytes: &[u8]) -> u32 {
+    let mut hash = fnv1a_continue_32(FNV_OFFSET_BASIS_32, bytes, bytes.len(), false);
+
+    let mut padding = (4 - bytes.len() % 4) % 4;
+    while padding > 0 {
+        hash = fnv1a_step_32(hash, 0);
+        padding -= 1;
+    }
+    hash
+}
+
+
+/// Computes 64-bits fnv1a hash folding ascii lowercase letters to uppercase only
+/// where the matching case_mask byte is non-zero.
+/// Bytes past the end of case_mask are not folded.
+pub const fn fnv1a_hash_64_masked_case(bytes: &[u8], case_mask: &[u8]) -> u64 {
+    let mut hash = FNV_OFFSET_BASIS_64;
+
+    let mut i = 0;
+    while i < bytes.len() {
+        let fold = i < case_mask.len() && case_mask[i] != 0;
+        hash ^= fold_ascii_letter(bytes[i], fold) as u64;
+        hash = hash.wrapping_mul(FNV_PRIME_64);
+        i += 1;
+    }
+    hash
+}
+
+
+/// Content-defined chunking scanner over a W bytes `FnvRollingHasher32` window.
+/// A boundary is signalled once the window is full and `hash & mask == trigger`,
+/// so a mask with k bits set gives an average chunk size of about 2^k bytes.
+#[derive(Clone, Copy, Debug)]
+pub struct FnvCdcScanner<const W: usize> {
+    hasher: FnvRollingHasher32<W>,
+    mask: u32,
+    trigger: u32,
+}
+
+impl<const W: usize> FnvCdcScanner<W> {
+    /// Creates a scanner with an empty window.
+    pub const fn new(mask: u32, trigger: u32) -> Self {
+        Self { hasher: FnvRollingHasher32::new(), mask, trigger }
+    }
+
+    /// Pushes a byte into the window and returns whether a chunk boundary follows it.
+    pub const fn push(&mut self, byte: u8) -> bool {
+        let hash = self.hasher.push_byte(byte);
+        self.hasher.filled == W && hash & self.mask == self.trigger
+    }
+}
+
+
+/// Maps a str to the value of the arm with the equal str literal, or the `_` arm otherwise.
+/// Like `fnv1a_dispatch!` but with 64-bit hashes, and arms whose hashes collide,
+/// including duplicate keys, are rejected at compile time.
+///
+/// ```
+/// let value = const_fnv1a_hash::static_dispatch!("beta" => {
+///     "alpha" => 1,
+///     "beta" => 2,
+///     _ => 0,
+/// });
+/// assert_eq!(value, 2);
+/// ```
+///
+/// ```compile_fail
+/// let value = const_fnv1a_hash::static_dispatch!("beta" => {
+///     "alpha" => 1,
+///     "alpha" => 2,
+///     _ => 0,
+/// });
+/// ```
+#[macro_export]
+macro_rules! static_dispatch {
+    ($input:expr => { $($key:literal => $value:expr,)* _ => $default:expr $(,)? }) => {{
+        const _: () = {
+            let hashes: &[u64] = &[$($crate::fnv1a_hash_str_64($key)),*];
+
+            let mut i = 0;
+            while i < hashes.len() {
+                let mut j = i + 1;
+                while j < hashes.len() {
+                    assert!(hashes[i] != hashes[j], "static_dispatch! arms have colliding hashes");
+                    j += 1;
+                }
+                i += 1;
+            }
+        };
+
+        let input: &str = $input;
+        #[allow(unused_variables)]
+        let hash = $crate::fnv1a_hash_str_64(input);
+        $(
+            if hash == { const HASH: u64 = $crate::fnv1a_hash_str_64($key); HASH } && input == $key {
+                $value
+            } else
+        )* {
+            $default
+        }
+    }};
+}
+
+
+/// Computes 64-bits fnv1a hash of a static str, memoized per thread by pointer and length.
+/// Only `'static` strs are accepted, as their addresses can never be reused for other content.
+#[cfg(feature = "std")]
+pub fn fnv1a_hash_str_64_memoized(input: &'static str) -> u64 {
+    use core::cell::RefCell;
+
+    std::thread_local! {
+        static CACHE: RefCell<FnvHashMap<(usize, usize), u64>> = RefCell::new(FnvHashMap::default());
+    }
+
+    let key = (input.as_ptr() as usize, input.len());
+    CACHE.with(|cache| *cache.borrow_mut().entry(key).or_insert_with(|| fnv1a_hash_str_64(input)))
+}
+
+
+/// Builds a deterministic UUID from the 128-bits fnv1a hash of the big-endian namespace bytes followed by name.
+/// The version nibble is set to 0xF to mark the UUID as non-standard, and the variant bits as per RFC 4122.
+pub const fn fnv1a_uuid_from_bytes(namespace: u64, name: &[u8]) -> [u8; 16] {
+    let namespace = namespace.to_be_bytes();
+    let mut hash = FNV_OFFSET_BASIS_128;
+
+    let mut i = 0;
+    while i < namespace.len() + name.len() {
+        let byte = if i < namespace.len() { namespace[i] } else { name[i - namespace.len()] };
+        hash ^= byte as u128;
+        hash = hash.wrapping_mul(FNV_PRIME_128);
+        i += 1;
+    }
+
+    let mut uuid = hash.to_be_bytes();
+    uuid[6] = (uuid[6] & 0x0F) | 0xF0;
+    uuid[8] = (uuid[8] & 0x3F) | 0x80;
+    uuid
+}
+
+
+/// Version byte mixed into the offset basis of `fnv1a_hash_64_stable`.
+const STABLE_HASH_VERSION: u8 = 1;
+
+/// Computes 64-bits fnv1a hash with the stable version byte XOR-ed into the offset basis.
+/// The output is guaranteed stable from version 1.0 onward, so it is safe to persist;
+/// any change to it requires a breaking version bump.
+#[inline(always)]
+pub const fn fnv1a_hash_64_stable(bytes: &[u8]) -> u64 {
+    let basis = FNV_OFFSET_BASIS_64 ^ STABLE_HASH_VERSION as u64;
+    fnv1a_continue_64(basis, bytes, bytes.len(), false)
+}
+
+
+/// Computes 64-bits fnv1a hash of the given slice from its last byte toward its first,
+/// covering up-to limit bytes from the end if provided.
+/// If limit is zero or exceeds slice length, slice length is used instead.
+pub const fn fnv1a_hash_64_reverse(bytes: &[u8], limit: Option<usize>) -> u64 {
+    let mut hash = FNV_OFFSET_BASIS_64;
+
+    let mut i = bytes.len();
+    let end = bytes.len() - effective_len(bytes, limit);
+
+    while i > end {
+        i -= 1;
+        hash ^= bytes[i] as u64;
+        hash = hash.wrapping_mul(FNV_PRIME_64);
+    }
+    hash
+}
+
+/// Computes 64-bit fnv1a hash of str from its last byte toward its first.
+#[inline(always)]
+pub const fn fnv1a_hash_str_64_reverse(input: &str) -> u64 {
+    fnv1a_hash_64_reverse(input.as_bytes(), None)
+}
+
+
+/// Bloom filter over WORDS * 64 bits using k positions derived from the 64-bits fnv1a hash.
+/// Positions use double hashing, `(h1 + i * h2) % bits`, where h1 and h2 are the
+/// high and low halves of the hash. A filter without words fails to compile.
+///
+/// ```compile_fail
+/// let filter = const_fnv1a_hash::FnvBloomFilter::<0>::new(3);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct FnvBloomFilter<const WORDS: usize> {
+    bits: [u64; WORDS],
+    k: u32,
+}
+
+impl<const WORDS: usize> FnvBloomFilter<WORDS> {
+    /// Amount of bits in the filter.
+    pub const BITS: usize = WORDS * 64;
+
+    /// Fails compilation for a filter without words, which would divide by zero on lookup.
+    const NON_EMPTY: () = assert!(WORDS > 0, "FnvBloomFilter needs at least one word");
+
+    /// Creates an empty filter setting k bits per inserted element.
+    pub const fn new(k: u32) -> Self {
+        let () = Self::NON_EMPTY;
+        Self { bits: [0; WORDS], k }
+    }
+
+    /// Returns the bit index of the i-th position for hash.
+    const fn position(hash: u64, i: u32) -> usize {
+        let h1 = hash >> 32;
+        let h2 = hash & 0xFFFF_FFFF;
+        (h1.wrapping_add((i as u64).wrapping_mul(h2)) % Self::BITS as u64) as usize
+    }
+
+    /// Inserts the given bytes into the filter.
+    pub const fn insert(&mut self, bytes: &[u8]) {
+        let hash = fnv1a_continue_64(FNV_OFFSET_BASIS_64, bytes, bytes.len(), false);
+
+        let mut i = 0;
+        while i < self.k {
+            let position = Self::position(hash, i);
+            self.bits[position / 64] |= 1 << (position % 64);
+            i += 1;
+        }
+    }
+
+    /// Returns whether the given bytes may have been inserted.
+    /// False positives are possible, false negatives are not.
+    pub const fn contains(&self, bytes: &[u8]) -> bool {
+        let hash = fnv1a_continue_64(FNV_OFFSET_BASIS_64, bytes, bytes.len(), false);
+
+        let mut i = 0;
+        while i < self.k {
+            let position = Self::position(hash, i);
+            if self.bits[position / 64] & (1 << (position % 64)) == 0 {
+                return false;
+            }
+            i += 1;
+        }
+        true
+    }
+
+    /// Returns the amount of set bits in the filter.
+    pub const fn bits_set(&self) -> u32 {
+        let mut count = 0;
+
+        let mut i = 0;
+        while i < WORDS {
+            count += self.bits[i].count_ones();
+            i += 1;
+        }
+        count
+    }
+}
+
+
+/// Computes both 32-bits and 64-bits fnv1a hashes of the given slice in a single pass,
+/// or up-to limit if provided.
+/// If limit is zero or exceeds slice length, slice length is used instead.
+/// If fold_to_upper is set, ascii lowercase letters are hashed as uppercase and all other bytes unchanged.
+pub const fn fnv1a_hash_32_and_64(bytes: &[u8], limit: Option<usize>, fold_to_upper: bool) -> (u32, u64) {
+    let mut hash_32 = FNV_OFFSET_BASIS_32;
+    let mut hash_64 = FNV_OFFSET_BASIS_64;
+
+    let mut i = 0;
+    let len = effective_len(bytes, limit);
+
+    while i < len {
+        let byte = fold_ascii_letter(bytes[i], fold_to_upper);
+        hash_32 = (hash_32 ^ byte as u32).wrapping_mul(FNV_PRIME_32);
+        hash_64 = (hash_64 ^ byte as u64).wrapping_mul(FNV_PRIME_64);
+        i += 1;
+    }
+    (hash_32, hash_64)
+}
+
+
+/// Computes 32-bits fnv1a hash of the given slice, or up-to limit if provided, along with
+/// the amount of bytes changed by case folding.
+/// Only ascii lowercase letters are folded, so uppercase letters, digits and punctuation count zero folded bytes.
+/// If limit is zero or exceeds slice length, slice length is used instead.
+pub const fn fnv1a_hash_32_debug(bytes: &[u8], limit: Option<usize>, fold_to_upper: bool) -> (u32, usize) {
+    let mut hash = FNV_OFFSET_BASIS_32;
+    let mut folded = 0;
+
+    let mut i = 0;
+    let len = effective_len(bytes, limit);
+
+    while i < len {
+        let byte = fold_ascii_letter(bytes[i], fold_to_upper);
+        folded += (byte != bytes[i]) as usize;
+        hash = fnv1a_step_32(hash, byte);
+        i += 1;
+    }
+    (hash, folded)
+}
+
+
+/// Computes 64-bits fnv1a hash of str along with the amount of grapheme clusters it contains.
+/// Bytes are hashed in order, so the hash equals `fnv1a_hash_str_64`.
+#[cfg(feature = "unicode")]
+pub fn fnv1a_hash_str_64_grapheme_count(input: &str) -> (u64, usize) {
+    use unicode_segmentation::UnicodeSegmentation;
+
+    input.graphemes(true).fold((FNV_OFFSET_BASIS_64, 0), |(hash, count), grapheme| {
+        (fnv1a_continue_64(hash, grapheme.as_bytes(), grapheme.len(), false), count + 1)
+    })
+}
+
+
+/// Computes 64-bits fnv1a hash of the given slice with depth hashed as a leading byte,
+/// so the same bytes at different nesting levels hash differently.
+#[inline(always)]
+pub const fn fnv1a_hash_64_with_depth(bytes: &[u8], depth: u8) -> u64 {
+    fnv1a_continue_64(fnv1a_step_64(FNV_OFFSET_BASIS_64, depth), bytes, bytes.len(), false)
+}
+
+
+/// Computes 64-bits fnv1a hash of a pair of strs, as with `fnv1a_hash_64_pair`.
+#[inline(always)]
+pub const fn fnv1a_hash_str_pair_64(a: &str, b: &str) -> u64 {
+    fnv1a_pair_64(a.as_bytes(), b.as_bytes(), false)
+}
+
+/// Computes 64-bits case-insensitive fnv1a hash of a pair of strs, as with `fnv1a_hash_64_pair`.
+/// Only ascii lowercase letters are folded, digits and punctuation are hashed unchanged.
+#[inline(always)]
+pub const fn fnv1a_hash_str_pair_case_64(a: &str, b: &str) -> u64 {
+    fnv1a_pair_64(a.as_bytes(), b.as_bytes(), true)
+}
+
+
+/// 64-bits fnv1a `core::fmt::Write` sink, hashing formatted output without allocating.
+/// Successive writes are hashed as if they were concatenated.
+#[derive(Clone, Copy, Debug)]
+pub struct FnvFmtHasher64 {
+    state: u64,
+}
+
+impl FnvFmtHasher64 {
+    /// Creates a hasher starting at the offset basis.
+    pub const fn new() -> Self {
+        Self { state: FNV_OFFSET_BASIS_64 }
+    }
+
+    /// Returns the hash of all str written so far.
+    pub const fn finalize(&self) -> u64 {
+        self.state
+    }
+}
+
+impl Default for FnvFmtHasher64 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl core::fmt::Write for FnvFmtHasher64 {
+    #[inline]
+    fn write_str(&mut self, input: &str) -> core::fmt::Result {
+        self.state = fnv1a_continue_64(self.state, input.as_bytes(), input.len(), false);
+        Ok(())
+    }
+}
+
+/// Computes 64-bits fnv1a hash of the `Display` output of value, without allocating.
+pub fn fnv1a_hash_display_64<T: core::fmt::Display>(value: T) -> u64 {
+    use core::fmt::Write;
+
+    let mut hasher = FnvFmtHasher64::new();
+    // Writing into the hasher never fails, only a broken Display impl could.
+    let _ = write!(hasher, "{}", value);
+    hasher.finalize()
+}
+
+
+/// Infinite iterator over open addressing probe positions, see `fnv1a_probe_sequence`.
+#[derive(Clone, Copy, Debug)]
+pub struct FnvProbeSequence {
+    position: usize,
+    step: usize,
+    table_size: usize,
+}
+
+impl Iterator for FnvProbeSequence {
+    type Item = usize;
+
+    #[inline]
+    fn next(&mut self) -> Option<usize> {
+        let position = self.position;
+        self.position = if self.position >= self.table_size - self.step {
+            self.position - (self.table_size - self.step)
+        } else {
+            self.position + self.step
+        };
+        Some(position)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+/// Returns the infinite double hashing probe sequence `(h1 + i * h2) % table_size` of bytes,
+/// where h1 is the 64-bits and h2 the 32-bits fnv1a hash with its lowest bit set.
+/// The sum is taken modulo table_size, so a prime table_size visits every slot once per
+/// table_size probes; a step that is a multiple of table_size is replaced by 1.
+/// Panics if table_size is zero.
+pub fn fnv1a_probe_sequence(bytes: &[u8], table_size: usize) -> FnvProbeSequence {
+    assert!(table_size != 0, "table_size must be non-zero");
+
+    let h1 = fnv1a_continue_64(FNV_OFFSET_BASIS_64, bytes, bytes.len(), false);
+    let h2 = fnv1a_continue_32(FNV_OFFSET_BASIS_32, bytes, bytes.len(), false) | 1;
+
+    let step = (h2 as u64 % table_size as u64) as usize;
+    FnvProbeSequence {
+        position: (h1 % table_size as u64) as usize,
+        step: if step == 0 { 1 } else { step },
+        table_size,
+    }
+}
+
+
+/// Computes 64-bits fnv1a hash folding ascii lowercase letters to uppercase only for
+/// bytes in the range [case_start, case_end).
+/// The range is clamped to the slice; an empty range folds nothing.
+pub const fn fnv1a_hash_64_case_range(bytes: &[u8], case_start: usize, case_end: usize) -> u64 {
+    let mut hash = FNV_OFFSET_BASIS_64;
+
+    let mut i = 0;
+    while i < bytes.len() {
+        let fold = i >= case_start && i < case_end;
+        hash ^= fold_ascii_letter(bytes[i], fold) as u64;
+        hash = hash.wrapping_mul(FNV_PRIME_64);
+        i += 1;
+    }
+    hash
+}
+
+
+/// Computes 32-bits fnv1a hash reduced modulo prime, for use as a polynomial hash residue.
+/// Panics if prime is zero.
+#[inline(always)]
+pub const fn fnv1a_hash_32_mod_prime(bytes: &[u8], prime: u32) -> u32 {
+    assert!(prime != 0, "prime must be non-zero");
+    fnv1a_continue_32(FNV_OFFSET_BASIS_32, bytes, bytes.len(), false) % prime
+}
+
+
+/// Computes 64-bits fnv1a hash along with which of its bits differ from the offset basis.
+/// `bits[i]` is set if bit i of the hash differs. Intended for avalanche analysis.
+pub fn fnv1a_hash_64_debug_bits(bytes: &[u8]) -> (u64, [bool; 64]) {
+    let hash = fnv1a_continue_64(FNV_OFFSET_BASIS_64, bytes, bytes.len(), false);
+    let diff = hash ^ FNV_OFFSET_BASIS_64;
+
+    let mut bits = [false; 64];
+    for (i, bit) in bits.iter_mut().enumerate() {
+        *bit = diff >> i & 1 == 1;
+    }
+    (hash, bits)
+}
+
+
+/// Computes 64-bits fnv1a hash of a and b interleaved byte-by-byte, `a[0], b[0], a[1], b[1], ...`.
+/// Once the shorter slice is exhausted, the rest of the longer slice is hashed in order.
+pub const fn fnv1a_hash_64_interleaved(a: &[u8], b: &[u8]) -> u64 {
+    let mut hash = FNV_OFFSET_BASIS_64;
+
+    let mut i = 0;
+    while i < a.len() || i < b.len() {
+        if i < a.len() {
+            hash = fnv1a_step_64(hash, a[i]);
+        }
+        if i < b.len() {
+            hash = fnv1a_step_64(hash, b[i]);
+        }
+        i += 1;
+    }
+    hash
+}
+
+
+/// Computes 64-bits fnv1a hash of a virtual_len zeroed buffer with the given (index, byte) pairs placed in it,
+/// without materializing the buffer. Pairs may be given in any order.
+/// Panics if an index is repeated or not below virtual_len.
+pub const fn fnv1a_hash_64_scatter(pairs: &[(usize, u8)], virtual_len: usize) -> u64 {
+    let mut i = 0;
+    while i < pairs.len() {
+        assert!(pairs[i].0 < virtual_len, "scatter index out of range");
+
+        let mut j = i + 1;
+        while j < pairs.len() {
+            assert!(pairs[i].0 != pairs[j].0, "duplicate scatter index");
+            j += 1;
+        }
+        i += 1;
+    }
+
+    let mut hash = FNV_OFFSET_BASIS_64;
+    let mut position = 0;
+
+    // Selects the pairs by ascending index, hashing the zero gaps in between.
+    let mut emitted = 0;
+    while emitted < pairs.len() {
+        let mut next = 0;
+        let mut found = false;
+
+        let mut i = 0;
+        while i < pairs.len() {
+            if pairs[i].0 >= position && (!found || pairs[i].0 < pairs[next].0) {
+                next = i;
+                found = true;
+            }
+            i += 1;
+        }
+
+        while position < pairs[next].0 {
+            hash = fnv1a_step_64(hash, 0);
+            position += 1;
+        }
+        hash = fnv1a_step_64(hash, pairs[next].1);
+        position += 1;
+        emitted += 1;
+    }
+
+    while position < virtual_len {
+        hash = fnv1a_step_64(hash, 0);
+        position += 1;
+    }
+    hash
+}
+
+
+/// Computes 32-bits fnv1a hash of every str in inputs.
+pub const fn fnv1a_hash_str_array_32<const N: usize>(inputs: &[&str; N]) -> [u32; N] {
+    let mut hashes = [0; N];
+
+    let mut i = 0;
+    while i < N {
+        hashes[i] = fnv1a_hash_str_32(inputs[i]);
+        i += 1;
+    }
+    hashes
+}
+
+/// Computes 64-bits fnv1a hash of every str in inputs.
+pub const fn fnv1a_hash_str_array_64<const N: usize>(inputs: &[&str; N]) -> [u64; N] {
+    let mut hashes = [0; N];
+
+    let mut i = 0;
+    while i < N {
+        hashes[i] = fnv1a_hash_str_64(inputs[i]);
+        i += 1;
+    }
+    hashes
+}
+
+
+/// Reverses `fnv1a_step_32`, recovering the previous state from the current one and the hashed byte.
+#[inline(always)]
+pub const fn fnv1a_undo_step_32(current_state: u32, byte: u8) -> u32 {
+    current_state.wrapping_mul(FNV_PRIME_32_INVERSE) ^ byte as u32
+}
+
+/// Reverses `fnv1a_step_64`, recovering the previous state from the current one and the hashed byte.
+#[inline(always)]
+pub const fn fnv1a_undo_step_64(current_state: u64, byte: u8) -> u64 {
+    current_state.wrapping_mul(FNV_PRIME_64_INVERSE) ^ byte as u64
+}
+
+
+/// Computes 64-bits fnv1a hash along with its amount of set bits, as an entropy diagnostic.
+#[inline(always)]
+pub const fn fnv1a_hash_64_count_ones(bytes: &[u8]) -> (u64, u32) {
+    let hash = fnv1a_continue_64(FNV_OFFSET_BASIS_64, bytes, bytes.len(), false);
+    (hash, hash.count_ones())
+}
+
+
+/// Computes 64-bit fnv1a hash of str with ascii letters folded to uppercase only before the first delimiter.
+/// The delimiter and everything after it are hashed unchanged; without a delimiter the whole str is folded.
+pub const fn fnv1a_hash_str_case_before_64(input: &str, delimiter: u8) -> u64 {
+    let bytes = input.as_bytes();
+
+    let mut split = 0;
+    while split < bytes.len() && bytes[split] != delimiter {
+        split += 1;
+    }
+
+    let hash = fnv1a_continue_range_letters_64(FNV_OFFSET_BASIS_64, bytes, 0, split);
+    fnv1a_continue_range_64(hash, bytes, split, bytes.len(), false)
+}
+
+
+/// Mask of the lowest N bits, failing compilation for N above 64.
+struct LowBitsMask<const N: u32>;
+
+impl<const N: u32> LowBitsMask<N> {
+    const MASK: u64 = {
+        assert!(N <= 64, "N must not exceed 64");
+        if N == 64 {
+            u64::MAX
+        } else {
+            (1 << N) - 1
+        }
+    };
+}
+
+/// Computes 64-bits fnv1a hash and returns its lowest N bits, zero for N of 0.
+/// N above 64 fails compilation.
+///
+/// ```compile_fail
+/// let _ = const_fnv1a_hash::fnv1a_hash_64_take_n::<65>(b"hello");
+/// ```
+#[inline(always)]
+pub const fn fnv1a_hash_64_take_n<const N: u32>(bytes: &[u8]) -> u64 {
+    fnv1a_continue_64(FNV_OFFSET_BASIS_64, bytes, bytes.len(), false) & LowBitsMask::<N>::MASK
+}
+
+
+/// Computes 64-bits fnv1a hash of only the bytes at the given positions, in the given order.
+/// Positions past the end of the slice hash a zero byte instead.
+pub const fn fnv1a_hash_64_sparse(bytes: &[u8], positions: &[usize]) -> u64 {
+    let mut hash = FNV_OFFSET_BASIS_64;
+
+    let mut i = 0;
+    while i < positions.len() {
+        let byte = if positions[i] < bytes.len() { bytes[positions[i]] } else { 0 };
+        hash = fnv1a_step_64(hash, byte);
+        i += 1;
+    }
+    hash
+}
+
+
+/// Continues 32-bits fnv1a hash over the little-endian bytes of each word.
+#[inline(always)]
+fn fnv1a_continue_words_32(mut hash: u32, words: &[u32]) -> u32 {
+    for &word in words {
+        let bytes = word.to_le_bytes();
+        hash = fnv1a_continue_32(hash, &bytes, bytes.len(), false);
+    }
+    hash
+}
+
+/// Computes 32-bits fnv1a hash of a word slice using the little-endian bytes of each word.
+pub fn fnv1a_hash_32_aligned(words: &[u32]) -> u32 {
+    fnv1a_continue_words_32(FNV_OFFSET_BASIS_32, words)
+}
+
+/// Applies 64-bits fnv1a rounds times, each round after the first hashing the
+/// little-endian bytes of the previous result from the offset basis.
+/// Zero rounds returns the offset basis, one round equals the standard hash.
+pub const fn fnv1a_hash_64_cascade(bytes: &[u8], rounds: u32) -> u64 {
+    if rounds == 0 {
+        return FNV_OFFSET_BASIS_64;
+    }
+
+    let mut hash = fnv1a_continue_64(FNV_OFFSET_BASIS_64, bytes, bytes.len(), false);
+
+    let mut round = 1;
+    while round < rounds {
+        hash = fnv1a_hash_u64_64(hash);
+        round += 1;
+    }
+    hash
+}
+
+
+/// Lowercase ascii hex digit for each nibble value.
+const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+/// Computes 32-bit fnv1a hash of str as 8 lowercase ascii hex characters.
+pub const fn fnv1a_hash_str_to_hex32(input: &str) -> [u8; 8] {
+    let hash = fnv1a_hash_str_32(input);
+    let mut hex = [0; 8];
+
+    let mut i = 0;
+    while i < hex.len() {
+        hex[i] = HEX_DIGITS[(hash >> (28 - 4 * i) & 0xF) as usize];
+        i += 1;
+    }
+    hex
+}
+
+/// Computes 64-bit fnv1a hash of str as 16 lowercase ascii hex characters.
+pub const fn fnv1a_hash_str_to_hex64(input: &str) -> [u8; 16] {
+    let hash = fnv1a_hash_str_64(input);
+    let mut hex = [0; 16];
+
+    let mut i = 0;
+    while i < hex.len() {
+        hex[i] = HEX_DIGITS[(hash >> (60 - 4 * i) & 0xF) as usize];
+        i += 1;
+    }
+    hex
+}
+
+
+/// Maps the 64-bits fnv1a hash of bytes onto one of n_buckets buckets using jump consistent hashing
+/// (Lamping & Veach). Growing n_buckets by one moves about 1/n_buckets of the keys, all to the new bucket.
+/// Panics if n_buckets is zero.
+pub const fn fnv1a_hash_64_consistent_bucket(bytes: &[u8], n_buckets: u32) -> u32 {
+    assert!(n_buckets != 0, "n_buckets must be non-zero");
+
+    let mut key = fnv1a_continue_64(FNV_OFFSET_BASIS_64, bytes, bytes.len(), false);
+    let mut bucket = 0;
+    let mut jump = 0;
+
+    while jump < n_buckets as u64 {
+        bucket = jump;
+        key = key.wrapping_mul(2862933555777941757).wrapping_add(1);
+        jump = ((bucket + 1) as f64 * ((1u64 << 31) as f64 / ((key >> 33) + 1) as f64)) as u64;
+    }
+    bucket as u32
+}
+
+
+/// Computes 64-bits fnv1a hash followed by the bijective Murmur3 `fmix64` finalizer.
+/// The output differs from `fnv1a_hash_64_case_sensitive`, but every input bit affects
+/// about half of the output bits.
+pub const fn fnv1a_hash_64_mixed(bytes: &[u8]) -> u64 {
+    let mut hash = fnv1a_continue_64(FNV_OFFSET_BASIS_64, bytes, bytes.len(), false);
+    hash ^= hash >> 33;
+    hash = hash.wrapping_mul(0xff51afd7ed558ccd);
+    hash ^= hash >> 33;
+    hash = hash.wrapping_mul(0xc4ceb9fe1a85ec53);
+    hash ^= hash >> 33;
+    hash
+}
+
+
+/// Computes 32-bit fnv1a hash of str, or up-to limit bytes if provided, along with the hashed prefix.
+/// If limit is zero or exceeds str length, the whole str is used instead.
+/// A limit inside a char is moved back to the start of that char, so the prefix stays a str.
+pub const fn fnv1a_hash_str_32_debug(input: &str, limit: Option<usize>) -> (u32, &str) {
+    let bytes = input.as_bytes();
+
+    let mut len = effective_len(bytes, limit);
+    while len < bytes.len() && bytes[len] & 0xC0 == 0x80 {
+        len -= 1;
+    }
+
+    let prefix = match core::str::from_utf8(bytes.split_at(len).0) {
+        Ok(prefix) => prefix,
+        Err(_) => unreachable!(),
+    };
+    (fnv1a_continue_32(FNV_OFFSET_BASIS_32, bytes, len, false), prefix)
+}
+
+
+/// Assigns bytes to the bucket of the first virtual node at or after its 64-bits fnv1a hash
+/// on the ring, wrapping around to the first node.
+/// virtual_nodes holds `(ring_position, bucket_id)` sorted by ring position.
+/// Panics if virtual_nodes is empty or not sorted.
+pub fn fnv1a_bucket_assign(bytes: &[u8], virtual_nodes: &[(u64, u32)]) -> u32 {
+    assert!(!virtual_nodes.is_empty(), "virtual_nodes must not be empty");
+    assert!(
+        virtual_nodes.windows(2).all(|pair| pair[0].0 <= pair[1].0),
+        "virtual_nodes must be sorted by ring position"
+    );
+
+    let hash = fnv1a_continue_64(FNV_OFFSET_BASIS_64, bytes, bytes.len(), false);
+    let index = virtual_nodes.partition_point(|&(position, _)| position < hash);
+    virtual_nodes.get(index).unwrap_or(&virtual_nodes[0]).1
+}
+
+
+/// Computes an order-independent, count-sensitive 32-bits fnv1a hash of the multiset of bytes.
+/// The little-endian u32 count of every byte value is hashed; the empty slice returns the offset basis.
+pub const fn fnv1a_hash_multiset_32(bytes: &[u8]) -> u32 {
+    if bytes.is_empty() {
+        return FNV_OFFSET_BASIS_32;
+    }
+
+    let mut counts = [0u32; 256];
+
+    let mut i = 0;
+    while i < bytes.len() {
+        counts[bytes[i] as usize] += 1;
+        i += 1;
+    }
+    fnv1a_hash_histogram_32(&counts)
+}
+
+/// Computes 32-bits fnv1a hash of the little-endian bytes of every count in a byte histogram.
+pub const fn fnv1a_hash_histogram_32(histogram: &[u32; 256]) -> u32 {
+    let mut hash = FNV_OFFSET_BASIS_32;
+
+    let mut byte = 0;
+    while byte < histogram.len() {
+        let count = histogram[byte].to_le_bytes();
+        hash = fnv1a_continue_32(hash, &count, count.len(), false);
+        byte += 1;
+    }
+    hash
+}
+
+
+/// Computes 64-bits fnv1a hash of the little-endian bytes of epoch followed by bytes,
+/// so bumping the epoch changes every hash.
+#[inline(always)]
+pub const fn fnv1a_hash_64_with_epoch(bytes: &[u8], epoch: u64) -> u64 {
+    let epoch = epoch.to_le_bytes();
+    let hash = fnv1a_continue_64(FNV_OFFSET_BASIS_64, &epoch, epoch.len(), false);
+    fnv1a_continue_64(hash, bytes, bytes.len(), false)
+}
+
+
+/// Returns a deterministic permutation of `0..N` derived from seed by a Fisher-Yates shuffle.
+/// The random stream starts at the 64-bits fnv1a hash of seed and each draw hashes
+/// the little-endian bytes of the previous one.
+pub const fn fnv1a_deterministic_shuffle<const N: usize>(seed: &[u8]) -> [usize; N] {
+    let mut permutation = [0; N];
+
+    let mut i = 0;
+    while i < N {
+        permutation[i] = i;
+        i += 1;
+    }
+
+    let mut hash = fnv1a_continue_64(FNV_OFFSET_BASIS_64, seed, seed.len(), false);
+
+    let mut i = N;
+    while i > 1 {
+        i -= 1;
+        hash = fnv1a_hash_u64_64(hash);
+
+        let j = reduce_64(hash, i + 1);
+        let swapped = permutation[i];
+        permutation[i] = permutation[j];
+        permutation[j] = swapped;
+    }
+    permutation
+}
+
+
+/// Computes 64-bit fnv1a hash of path segments, each preceded by a `/` separator byte,
+/// so `["a", "b"]` hashes as `/a/b` and differs from `["ab"]`.
+/// An empty segment list returns the offset basis.
+pub const fn fnv1a_hash_path_64(segments: &[&str]) -> u64 {
+    let mut hash = FNV_OFFSET_BASIS_64;
+
+    let mut i = 0;
+    while i < segments.len() {
+        let segment = segments[i].as_bytes();
+        hash = fnv1a_step_64(hash, b'/');
+        hash = fnv1a_continue_64(hash, segment, segment.len(), false);
+        i += 1;
+    }
+    hash
+}
+
+
+/// Computes 32-bit fnv1a hash of str with ascii letters folded to uppercase in the first case_up_to bytes
+/// and the rest hashed verbatim. If case_up_to exceeds str length, the whole str is folded.
+pub const fn fnv1a_hash_str_32_case_boundary(input: &str, case_up_to: usize) -> u32 {
+    let bytes = input.as_bytes();
+    let split = clamp_len(bytes, case_up_to);
+
+    let hash = fnv1a_continue_range_letters_32(FNV_OFFSET_BASIS_32, bytes, 0, split);
+    fnv1a_continue_range_32(hash, bytes, split, bytes.len(), false)
+}
+
+
+/// Builds a table of `(hash, index)` pairs for keys, sorted by 64-bits fnv1a hash for `fnv1a_dispatch_lookup`.
+pub const fn fnv1a_build_dispatch_table<const N: usize>(keys: &[&str; N]) -> [(u64, usize); N] {
+    let mut table = [(0, 0); N];
+
+    let mut i = 0;
+    while i < N {
+        let entry = (fnv1a_hash_str_64(keys[i]), i);
+
+        // Insertion sort, shifting larger hashes up.
+        let mut j = i;
+        while j > 0 && table[j - 1].0 > entry.0 {
+            table[j] = table[j - 1];
+            j -= 1;
+        }
+        table[j] = entry;
+        i += 1;
+    }
+    table
+}
+
+/// Returns the index of key in a table built by `fnv1a_build_dispatch_table` from keys.
+/// Entries with a matching hash are confirmed against keys, so colliding strs are not confused.
+pub const fn fnv1a_dispatch_lookup(table: &[(u64, usize)], keys: &[&str], key: &str) -> Option<usize> {
+    let hash = fnv1a_hash_str_64(key);
+
+    let mut low = 0;
+    let mut high = table.len();
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if table[mid].0 < hash {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+
+    while low < table.len() && table[low].0 == hash {
+        let index = table[low].1;
+        if index < keys.len() && bytes_eq(keys[index].as_bytes(), key.as_bytes()) {
+            return Some(index);
+        }
+        low += 1;
+    }
+    None
+}
+
+
+/// Counts how often each output bit is set over the 64-bits fnv1a hashes of all 256 single bytes.
+/// An unbiased hash sets every bit about 128 times. A single fnv1a round only mixes the input
+/// byte into a few bit ranges, so many of the upper bits stay constant (0 or 256) over this corpus.
+pub const fn fnv1a_hash_64_bit_frequency_check() -> [u32; 64] {
+    let mut counts = [0; 64];
+
+    let mut byte = 0;
+    while byte < 256 {
+        let hash = fnv1a_step_64(FNV_OFFSET_BASIS_64, byte as u8);
+
+        let mut bit = 0;
+        while bit < 64 {
+            counts[bit] += (hash >> bit & 1) as u32;
+            bit += 1;
+        }
+        byte += 1;
+    }
+    counts
+}
+
+
+/// Computes 64-bit fnv1a hash of a i8 after ZigZag encoding it, so small magnitudes of either
+/// sign map to small unsigned values.
+#[inline(always)]
+pub const fn fnv1a_hash_i8_zigzag_64(value: i8) -> u64 {
+    fnv1a_hash_u8_64(((value << 1) ^ (value >> 7)) as u8)
+}
+
+/// Computes 64-bit fnv1a hash of a i16 after ZigZag encoding it.
+#[inline(always)]
+pub const fn fnv1a_hash_i16_zigzag_64(value: i16) -> u64 {
+    fnv1a_hash_u16_64(((value << 1) ^ (value >> 15)) as u16)
+}
+
+/// Computes 64-bit fnv1a hash of a i32 after ZigZag encoding it.
+#[inline(always)]
+pub const fn fnv1a_hash_i32_zigzag_64(value: i32) -> u64 {
+    fnv1a_hash_u32_64(((value << 1) ^ (value >> 31)) as u32)
+}
+
+/// Computes 64-bit fnv1a hash of a i64 after ZigZag encoding it.
+#[inline(always)]
+pub const fn fnv1a_hash_i64_zigzag_64(value: i64) -> u64 {
+    fnv1a_hash_u64_64(((value << 1) ^ (value >> 63)) as u64)
+}
+
+
+/// Yields `(line_number, hash)` for each `\n`-delimited line of the input, numbered from 0.
+/// The `\n` itself is not hashed, but a `\r` before it is, so `\r\n` lines hash differently from `\n` lines.
+pub fn fnv1a_hash_lines_64(input: &str) -> impl Iterator<Item = (usize, u64)> + '_ {
+    input
+        .split('\n')
+        .enumerate()
+        .map(|(line, text)| (line, fnv1a_hash_str_64(text)))
+}
+
+
+/// Borrows a value as the bytes hashed for it by [`fnv1a_struct_fields!`].
+pub trait ToBytes {
+    /// Returns the bytes representing the value.
+    fn as_bytes(&self) -> &[u8];
+}
+
+impl ToBytes for str {
+    #[inline(always)]
+    fn as_bytes(&self) -> &[u8] {
+        str::as_bytes(self)
+    }
+}
+
+impl ToBytes for [u8] {
+    #[inline(always)]
+    fn as_bytes(&self) -> &[u8] {
+        self
+    }
+}
+
+impl<const N: usize> ToBytes for [u8; N] {
+    #[inline(always)]
+    fn as_bytes(&self) -> &[u8] {
+        self
+    }
+}
+
+impl ToBytes for u8 {
+    #[inline(always)]
+    fn as_bytes(&self) -> &[u8] {
+        core::slice::from_ref(self)
+    }
+}
+
+impl<T: ToBytes + ?Sized> ToBytes for &T {
+    #[inline(always)]
+    fn as_bytes(&self) -> &[u8] {
+        (**self).as_bytes()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl ToBytes for alloc::string::String {
+    #[inline(always)]
+    fn as_bytes(&self) -> &[u8] {
+        self.as_str().as_bytes()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl ToBytes for alloc::vec::Vec<u8> {
+    #[inline(always)]
+    fn as_bytes(&self) -> &[u8] {
+        self
+    }
+}
+
+/// Hashes the listed fields of a struct into an array of `(field_name_hash, field_value_hash)` pairs.
+/// Names are hashed at compile time with [`fnv1a_hash_str_64`], values through [`ToBytes`].
+///
+/// ```
+/// struct Packet { kind: u8, name: &'static str }
+///
+/// let packet = Packet { kind: 1, name: "ping" };
+/// let fields = const_fnv1a_hash::fnv1a_struct_fields!(packet, kind, name);
+/// assert_eq!(fields[1].0, const_fnv1a_hash::fnv1a_hash_str_64("name"));
+/// assert_eq!(fields[1].1, const_fnv1a_hash::fnv1a_hash_str_64("ping"));
+/// ```
+#[macro_export]
+macro_rules! fnv1a_struct_fields {
+    ($value:expr, $($field:ident),+ $(,)?) => {{
+        let value = &$value;
+        [$(
+            (
+                { const NAME: u64 = $crate::fnv1a_hash_str_64(stringify!($field)); NAME },
+                $crate::fnv1a_hash_64_case_sensitive($crate::ToBytes::as_bytes(&value.$field), None),
+            ),
+        )+]
+    }};
+}
+
+
+/// 32-bits fnv1a hashes of the components of a URL, see [`fnv1a_hash_url_32`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct UrlHash {
+    /// Hash of the part before `://`, or of the empty str if there is none.
+    pub scheme: u32,
+    /// Hash of the part after the scheme up to the first `/` or `?`.
+    pub host: u32,
+    /// Hash of the part from the first `/` after the host up to `?`, including the leading `/`.
+    pub path: u32,
+    /// Hash of the part after the first `?`, excluding the `?`.
+    pub query: u32,
+    /// Hash of the whole URL.
+    pub combined: u32,
+}
+
+/// Splits a URL on `://`, `/` and `?` in a single pass and hashes each component with 32-bits fnv1a.
+/// Components that are absent hash as the empty str.
+pub const fn fnv1a_hash_url_32(url: &str) -> UrlHash {
+    let bytes = url.as_bytes();
+
+    let mut host_start = 0;
+    let mut scheme_end = 0;
+    let mut i = 0;
+    while i + 2 < bytes.len() {
+        if bytes[i] == b':' && bytes[i + 1] == b'/' && bytes[i + 2] == b'/' {
+            scheme_end = i;
+            host_start = i + 3;
+            break;
+        }
+        if bytes[i] == b'/' || bytes[i] == b'?' {
+            break;
+        }
+        i += 1;
+    }
+
+    let mut path_start = host_start;
+    while path_start < bytes.len() && bytes[path_start] != b'/' && bytes[path_start] != b'?' {
+        path_start += 1;
+    }
+    let mut query_start = path_start;
+    while query_start < bytes.len() && bytes[query_start] != b'?' {
+        query_start += 1;
+    }
+
+    let query = if query_start < bytes.len() {
+        fnv1a_continue_range_32(FNV_OFFSET_BASIS_32, bytes, query_start + 1, bytes.len(), false)
+    } else {
+        FNV_OFFSET_BASIS_32
+    };
+
+    UrlHash {
+        scheme: fnv1a_continue_range_32(FNV_OFFSET_BASIS_32, bytes, 0, scheme_end, false),
+        host: fnv1a_continue_range_32(FNV_OFFSET_BASIS_32, bytes, host_start, path_start, false),
+        path: fnv1a_continue_range_32(FNV_OFFSET_BASIS_32, bytes, path_start, query_start, false),
+        query,
+        combined: fnv1a_hash_str_32(url),
+    }
+}
+
+
+/// Computes 64-bits fnv1a hash of the first bit_len bits of a bitvector stored as little-endian
+/// words. Only the bytes covering bit_len are hashed and bits at or above bit_len are zeroed,
+/// so padding bits never affect the hash. bit_len is clamped to the bits available in words.
+pub const fn fnv1a_hash_bitvector_64(words: &[u64], bit_len: usize) -> u64 {
+    let bit_len = if bit_len > words.len() * 64 { words.len() * 64 } else { bit_len };
+    let byte_len = bit_len.div_ceil(8);
+
+    let mut hash = FNV_OFFSET_BASIS_64;
+    let mut i = 0;
+    while i < byte_len {
+        let mut byte = (words[i / 8] >> (i % 8 * 8)) as u8;
+        let remaining = bit_len - i * 8;
+        if remaining < 8 {
+            byte &= (1u8 << remaining) - 1;
+        }
+        hash = fnv1a_step_64(hash, byte);
+        i += 1;
+    }
+    hash
+}
+
+
+/// Computes the xor of the 64-bits fnv1a hashes of a and b.
+#[inline(always)]
+pub const fn fnv1a_hash_delta_64(a: &[u8], b: &[u8]) -> u64 {
+    fnv1a_hash_64_case_sensitive(a, None) ^ fnv1a_hash_64_case_sensitive(b, None)
+}
+
+/// Computes the number of bits that differ between the 64-bits fnv1a hashes of a and b.
+#[inline(always)]
+pub const fn fnv1a_hash_hamming_distance_64(a: &[u8], b: &[u8]) -> u32 {
+    fnv1a_hash_delta_64(a, b).count_ones()
+}
+
+
+/// Computes 64-bits fnv1a hash of window, a `0x00` separator and lookahead in one pass.
+/// The separator keeps `("abc", "def")` apart from `("abcdef", "")`, but a window ending in
+/// `0x00` can still collide with a shorter one; use [`fnv1a_hash_64_length_separated`] if
+/// the content may contain zero bytes.
+#[inline(always)]
+pub const fn fnv1a_hash_window_64(window: &[u8], lookahead: &[u8]) -> u64 {
+    let hash = fnv1a_continue_64(FNV_OFFSET_BASIS_64, window, window.len(), false);
+    let hash = fnv1a_step_64(hash, 0);
+    fnv1a_continue_64(hash, lookahead, lookahead.len(), false)
+}
+
+
+/// Computes 64-bits fnv1a hash of bools packed 8 per byte, least significant bit first,
+/// with the final byte zero-padded. The length is hashed first as 8 little-endian bytes so
+/// trailing false values are not lost in the padding.
+pub const fn fnv1a_hash_bool_slice_64(bools: &[bool]) -> u64 {
+    let len = (bools.len() as u64).to_le_bytes();
+    let mut hash = fnv1a_continue_64(FNV_OFFSET_BASIS_64, &len, len.len(), false);
+
+    let mut i = 0;
+    while i < bools.len() {
+        let mut byte = 0u8;
+        let mut bit = 0;
+        while bit < 8 && i + bit < bools.len() {
+            byte |= (bools[i + bit] as u8) << bit;
+            bit += 1;
+        }
+        hash = fnv1a_step_64(hash, byte);
+        i += 8;
+    }
+    hash
+}
+
+
+/// Computes 32-bits fnv1a hash of a camelCase or PascalCase identifier as if it were snake_case.
+/// An underscore is hashed before every ASCII uppercase letter that is not at the start and does
+/// not follow an underscore, and letters are lowercased, so `"UserName"` hashes as `"user_name"`.
+/// Runs of capitals are split per letter: `"HTML"` hashes as `"h_t_m_l"`.
+pub const fn fnv1a_hash_str_32_camel_to_snake(input: &str) -> u32 {
+    let bytes = input.as_bytes();
+    let mut hash = FNV_OFFSET_BASIS_32;
+
+    let mut i = 0;
+    while i < bytes.len() {
+        let byte = bytes[i];
+        if byte.is_ascii_uppercase() && i > 0 && bytes[i - 1] != b'_' {
+            hash = fnv1a_step_32(hash, b'_');
+        }
+        hash = fnv1a_step_32(hash, byte.to_ascii_lowercase());
+        i += 1;
+    }
+    hash
+}
+
+
+/// Derives N seeds from master by repeatedly hashing the little-endian bytes of the previous value
+/// with 64-bits fnv1a, starting from master.
+pub const fn fnv1a_derive_seeds_64<const N: usize>(master: u64) -> [u64; N] {
+    let mut seeds = [0; N];
+
+    let mut previous = master;
+    let mut i = 0;
+    while i < N {
+        previous = fnv1a_hash_u64_64(previous);
+        seeds[i] = previous;
+        i += 1;
+    }
+    seeds
+}
+
+
+/// Computes 64-bits fnv1a hash with each byte multiplied by `weights[i % weights.len()]`,
+/// wrapping, before it is mixed in. Empty weights hash the bytes unchanged.
+pub const fn fnv1a_hash_64_weighted(bytes: &[u8], weights: &[u8]) -> u64 {
+    if weights.is_empty() {
+        return fnv1a_continue_64(FNV_OFFSET_BASIS_64, bytes, bytes.len(), false);
+    }
+
+    let mut hash = FNV_OFFSET_BASIS_64;
+
+    let mut i = 0;
+    while i < bytes.len() {
+        hash = fnv1a_step_64(hash, bytes[i].wrapping_mul(weights[i % weights.len()]));
+        i += 1;
+    }
+    hash
+}
+
+
+/// Computes case-insensitive 64-bits fnv1a hash of a str.
+/// Only ASCII letters are folded, so the bytes of multi-byte UTF-8 sequences are hashed unchanged.
+#[inline(always)]
+pub const fn fnv1a_hash_str_64_safe(input: &str) -> u64 {
+    fnv1a_hash_64_ascii_case_insensitive(input.as_bytes(), None)
+}
+
+
+/// Lazily yields k 32-bits fnv1a hashes of bytes, the i-th seeded with `FNV_OFFSET_BASIS_32 ^ i`.
+/// The first hash is the plain fnv1a hash.
+pub fn fnv1a_multi_hash_32(bytes: &[u8], k: usize) -> impl Iterator<Item = u32> + '_ {
+    (0..k).map(move |i| fnv1a_continue_32(FNV_OFFSET_BASIS_32 ^ i as u32, bytes, bytes.len(), false))
+}
+
+
+/// Computes 64-bits fnv1a hash of bytes with the upper and lower nibble of each byte swapped.
+pub const fn fnv1a_hash_nibble_swapped_64(bytes: &[u8]) -> u64 {
+    let mut hash = FNV_OFFSET_BASIS_64;
+
+    let mut i = 0;
+    while i < bytes.len() {
+        hash = fnv1a_step_64(hash, bytes[i].rotate_left(4));
+        i += 1;
+    }
+    hash
+}
+
+
+/// Computes 32-bits fnv1a hash of the little-endian bytes of file_id followed by block_index.
+#[inline(always)]
+pub const fn fnv1a_hash_file_block_32(file_id: u32, block_index: u32) -> u32 {
+    let [f0, f1, f2, f3] = file_id.to_le_bytes();
+    let [b0, b1, b2, b3] = block_index.to_le_bytes();
+    fnv1a_hash_pack8_32(f0, f1, f2, f3, b0, b1, b2, b3)
+}
+
+/// Computes 64-bits fnv1a hash of the little-endian bytes of file_id followed by block_index.
+#[inline(always)]
+pub const fn fnv1a_hash_file_block_64(file_id: u32, block_index: u32) -> u64 {
+    fnv1a_hash_u64_64((file_id as u64) | (block_index as u64) << 32)
+}
+
+
+/// Continues a 64-bits fnv1a hash from prev_hash over new_bytes, as if they had been appended
+/// to the input that produced prev_hash. Start from `FNV_OFFSET_BASIS_64` for a fresh hash.
+#[inline(always)]
+pub const fn fnv1a_hash_64_continue(prev_hash: u64, new_bytes: &[u8]) -> u64 {
+    fnv1a_continue_64(prev_hash, new_bytes, new_bytes.len(), false)
+}
+
+
+/// Computes 64-bits fnv1a hash of bytes with the bit order of each byte reversed.
+pub const fn fnv1a_hash_bit_reversed_64(bytes: &[u8]) -> u64 {
+    let mut hash = FNV_OFFSET_BASIS_64;
+
+    let mut i = 0;
+    while i < bytes.len() {
+        hash = fnv1a_step_64(hash, bytes[i].reverse_bits());
+        i += 1;
+    }
+    hash
+}
+
+
+/// Computes 64-bits fnv1a hash of the grapheme clusters of str with a `0x1F` unit separator
+/// between consecutive clusters. Canonically equivalent strs with different bytes, like
+/// `"e\u{301}"` and `"\u{e9}"`, hash differently unless normalized first.
+#[cfg(feature = "unicode")]
+pub fn fnv1a_hash_str_64_graphemes(input: &str) -> u64 {
+    use unicode_segmentation::UnicodeSegmentation;
+
+    let mut graphemes = input.graphemes(true);
+    let first = match graphemes.next() {
+        Some(grapheme) => grapheme,
+        None => return FNV_OFFSET_BASIS_64,
+    };
+
+    let hash = fnv1a_continue_64(FNV_OFFSET_BASIS_64, first.as_bytes(), first.len(), false);
+    graphemes.fold(hash, |hash, grapheme| {
+        fnv1a_continue_64(fnv1a_step_64(hash, 0x1F), grapheme.as_bytes(), grapheme.len(), false)
+    })
+}
+
+
+/// Computes 64-bits fnv1a hash of bytes repeated times in sequence, without allocating.
+pub const fn fnv1a_hash_64_repeated(bytes: &[u8], times: usize) -> u64 {
+    let mut hash = FNV_OFFSET_BASIS_64;
+
+    let mut i = 0;
+    while i < times {
+        hash = fnv1a_continue_64(hash, bytes, bytes.len(), false);
+        i += 1;
+    }
+    hash
+}
+
+
+/// Fixed-size open-addressed map from static strs to values, built at compile time.
+/// Slots are selected by the 32-bits fnv1a hash of the key with linear probing, and lookups
+/// confirm the key after a hash match. Keep the load factor at or below 1/2, e.g. 8 entries
+/// in 16 slots, so probe sequences stay short. Usually created through the `const_map!` macro.
+#[derive(Clone, Copy, Debug)]
+pub struct FnvMap<K, V, const N: usize> {
+    slots: [Option<(u32, K, V)>; N],
+}
+
+impl<V: Copy, const N: usize> FnvMap<&'static str, V, N> {
+    /// Creates a map from the given entries.
+    /// Panics, failing compilation in a const context, if there are more entries than slots
+    /// or two keys have the same 32-bits hash, which includes duplicate keys.
+    pub const fn new<const M: usize>(entries: [(&'static str, V); M]) -> Self {
+        assert!(M <= N, "FnvMap has fewer slots than entries, increase the table size");
+        let mut slots = [None; N];
+
+        let mut i = 0;
+        while i < M {
+            let (key, value) = entries[i];
+            let hash = fnv1a_hash_str_32(key);
+
+            let mut slot = hash as usize % N;
+            while let Some((existing, _, _)) = slots[slot] {
+                assert!(existing != hash, "FnvMap keys have colliding hashes");
+                slot = (slot + 1) % N;
+            }
+            slots[slot] = Some((hash, key, value));
+            i += 1;
+        }
+        Self { slots }
+    }
+
+    /// Returns the value stored for key, if any.
+    pub const fn get(&self, key: &str) -> Option<&V> {
+        if N == 0 {
+            return None;
+        }
+        let hash = fnv1a_hash_str_32(key);
+
+        let mut slot = hash as usize % N;
+        let mut probes = 0;
+        while probes < N {
+            match &self.slots[slot] {
+                Some((existing, stored, value)) if *existing == hash => {
+                    return if bytes_eq((*stored).as_bytes(), key.as_bytes()) { Some(value) } else { None };
+                }
+                Some(_) => slot = (slot + 1) % N,
+                None => return None,
+            }
+            probes += 1;
+        }
+        None
+    }
+}
+
+/// Declares a `FnvMap` constant built at compile time.
+///
+/// ```
+/// const_fnv1a_hash::const_map!(TABLE: const_fnv1a_hash::FnvMap<&str, u32, 16> = {
+///     "alpha" => 1,
+///     "beta" => 2,
+/// });
+///
+/// assert_eq!(TABLE.get("alpha"), Some(&1));
+/// assert_eq!(TABLE.get("gamma"), None);
+/// ```
+///
+/// ```compile_fail
+/// const_fnv1a_hash::const_map!(TABLE: const_fnv1a_hash::FnvMap<&str, u32, 16> = {
+///     "alpha" => 1,
+///     "alpha" => 2,
+/// });
+/// # let _ = TABLE;
+/// ```
+#[macro_export]
+macro_rules! const_map {
+    ($vis:vis $name:ident : $ty:ty = { $($key:expr => $value:expr),* $(,)? }) => {
+        $vis const $name: $ty = <$ty>::new([$(($key, $value)),*]);
+    };
+}
+
+
+/// Builds the Gear hash table for content-defined chunking, holding the 64-bits fnv1a hash
+/// of each single byte. Equal to [`FNV1A_BYTE_TABLE_64`].
+#[inline(always)]
+pub const fn fnv1a_gear_build_table() -> [u64; 256] {
+    build_table_64()
+}
+
+/// Rolls a byte into a Gear hash state. Bits older than 64 bytes are shifted out of the state.
+#[inline(always)]
+pub const fn fnv1a_gear_roll(state: u64, byte: u8, table: &[u64; 256]) -> u64 {
+    (state << 1) ^ table[byte as usize]
+}
+
+
+/// Computes 64-bits fnv1a hash of the little-endian bytes of a length, for use as a cheap pre-filter.
+#[inline(always)]
+pub const fn fnv1a_hash_length_64(len: usize) -> u64 {
+    fnv1a_hash_usize_64(len)
+}
+
+/// Returns whether bytes has expected_len bytes and the 64-bits fnv1a hash expected_hash.
+/// The hash is only computed if the length matches.
+#[inline(always)]
+pub const fn fnv1a_hash_64_with_length_check(bytes: &[u8], expected_hash: u64, expected_len: usize) -> bool {
+    bytes.len() == expected_len && fnv1a_hash_64_case_sensitive(bytes, None) == expected_hash
+}
+
+
+/// Evaluates the polynomial with the given coefficients, lowest degree first, at x modulo prime
+/// using Horner's method. An x of 0 is replaced by `FNV_OFFSET_BASIS_32`, so the default
+/// evaluation point is seeded by fnv1a. Panics if prime is 0.
+pub const fn fnv1a_poly_eval_32(coefficients: &[u8], x: u32, prime: u32) -> u32 {
+    let x = if x == 0 { FNV_OFFSET_BASIS_32 } else { x } as u64 % prime as u64;
+    let prime = prime as u64;
+
+    let mut acc = 0u64;
+    let mut i = coefficients.len();
+    while i > 0 {
+        i -= 1;
+        acc = (acc * x + coefficients[i] as u64) % prime;
+    }
+    acc as u32
+}
+
+
+/// Computes 64-bits fnv1a hash with the state rotated right by 13 bits after every 8 bytes,
+/// improving diffusion on periodic inputs. Inputs shorter than 8 bytes hash as plain fnv1a.
+pub const fn fnv1a_hash_64_rotated(bytes: &[u8]) -> u64 {
+    let mut hash = FNV_OFFSET_BASIS_64;
+
+    let mut i = 0;
+    while i < bytes.len() {
+        hash = fnv1a_step_64(hash, bytes[i]);
+        i += 1;
+        if i % 8 == 0 {
+            hash = hash.rotate_right(13);
+        }
+    }
+    hash
+}
+
+
+/// Code point ranges counted as emoji by [`fnv1a_hash_str_64_with_emoji_count`], sorted by start.
+/// Covers the main pictographic blocks; joiners, variation selectors and keycap bases are not counted.
+#[cfg(feature = "unicode")]
+const EMOJI_RANGES: [(u32, u32); 8] = [
+    (0x2600, 0x26FF),
+    (0x2700, 0x27BF),
+    (0x1F1E6, 0x1F1FF),
+    (0x1F300, 0x1F5FF),
+    (0x1F600, 0x1F64F),
+    (0x1F680, 0x1F6FF),
+    (0x1F900, 0x1F9FF),
+    (0x1FA70, 0x1FAFF),
+];
+
+/// Computes 64-bits fnv1a hash of str along with the amount of emoji code points it contains,
+/// decoding code points in the same pass over the bytes. The hash equals `fnv1a_hash_str_64`.
+#[cfg(feature = "unicode")]
+pub const fn fnv1a_hash_str_64_with_emoji_count(input: &str) -> (u64, usize) {
+    let bytes = input.as_bytes();
+    let mut hash = FNV_OFFSET_BASIS_64;
+    let mut count = 0;
+
+    let mut code_point = 0u32;
+    let mut remaining = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        let byte = bytes[i];
+        hash = fnv1a_step_64(hash, byte);
+
+        if remaining > 0 {
+            code_point = code_point << 6 | (byte & 0x3F) as u32;
+            remaining -= 1;
+        } else if byte >= 0xF0 {
+            code_point = (byte & 0x07) as u32;
+            remaining = 3;
+        } else if byte >= 0xE0 {
+            code_point = (byte & 0x0F) as u32;
+            remaining = 2;
+        } else if byte >= 0xC0 {
+            code_point = (byte & 0x1F) as u32;
+            remaining = 1;
+        } else {
+            code_point = byte as u32;
+        }
+
+        if remaining == 0 {
+            let mut range = 0;
+            while range < EMOJI_RANGES.len() && EMOJI_RANGES[range].0 <= code_point {
+                if code_point <= EMOJI_RANGES[range].1 {
+                    count += 1;
+                    break;
+                }
+                range += 1;
+            }
+        }
+        i += 1;
+    }
+    (hash, count)
+}
+
+
+/// Computes the 64-bits fnv1a hashes of every non-empty prefix of input in one pass, so element i
+/// is the hash of the first i + 1 bytes. Panics, failing compilation in a const context, if N
+/// differs from the length of input.
+pub const fn fnv1a_str_prefix_hashes_64<const N: usize>(input: &str) -> [u64; N] {
+    assert!(N == input.len(), "prefix hash array length must equal input length");
+    let bytes = input.as_bytes();
+    let mut hashes = [0; N];
+
+    let mut hash = FNV_OFFSET_BASIS_64;
+    let mut i = 0;
+    while i < N {
+        hash = fnv1a_step_64(hash, bytes[i]);
+        hashes[i] = hash;
+        i += 1;
+    }
+    hashes
+}
+
+
+/// Computes 32-bits fnv1a hash of the given slice, or up-to limit if provided, along with the
+/// amount of bytes hashed. Unlike `fnv1a_hash_32_case_sensitive`, a limit of zero hashes nothing
+/// and returns `(FNV_OFFSET_BASIS_32, 0)`; a limit exceeding slice length hashes the whole slice.
+#[inline(always)]
+pub const fn fnv1a_hash_32_counted(bytes: &[u8], limit: Option<usize>) -> (u32, usize) {
+    let len = match limit {
+        Some(limit) => clamp_len(bytes, limit),
+        None => bytes.len(),
+    };
+    (fnv1a_continue_32(FNV_OFFSET_BASIS_32, bytes, len, false), len)
+}
+
+
+/// Computes 64-bits fnv1a hash of bytes with each byte xored with xor_key first.
+/// An xor_key of 0 gives the standard hash.
+pub const fn fnv1a_hash_64_xor_preprocessed(bytes: &[u8], xor_key: u8) -> u64 {
+    let mut hash = FNV_OFFSET_BASIS_64;
+
+    let mut i = 0;
+    while i < bytes.len() {
+        hash = fnv1a_step_64(hash, bytes[i] ^ xor_key);
+        i += 1;
+    }
+    hash
+}
+
+
+/// Error returned by [`fnv1a_hash_base64_64`] for malformed input.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Base64Error {
+    /// A byte outside the standard base64 alphabet.
+    InvalidChar(u8),
+    /// The input length is not a multiple of 4.
+    InvalidLength,
+    /// `=` padding appears outside the last two positions of the final group,
+    /// or the character before it has bits set that do not belong to a decoded byte.
+    InvalidPadding,
+}
+
+impl core::fmt::Display for Base64Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::InvalidChar(byte) => write!(f, "invalid base64 character 0x{:02x}", byte),
+            Self::InvalidLength => f.write_str("base64 input length is not a multiple of 4"),
+            Self::InvalidPadding => f.write_str("invalid base64 padding"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Base64Error {}
+
+/// Returns the 6-bit value of a standard base64 character.
+const fn base64_value(byte: u8) -> Result<u32, Base64Error> {
+    match byte {
+        b'A'..=b'Z' => Ok((byte - b'A') as u32),
+        b'a'..=b'z' => Ok((byte - b'a' + 26) as u32),
+        b'0'..=b'9' => Ok((byte - b'0' + 52) as u32),
+        b'+' => Ok(62),
+        b'/' => Ok(63),
+        b'=' => Err(Base64Error::InvalidPadding),
+        _ => Err(Base64Error::InvalidChar(byte)),
+    }
+}
+
+/// Computes 64-bits fnv1a hash of the bytes decoded from padded standard base64,
+/// decoding each group of 4 characters on the fly without allocating. Only canonical
+/// encodings are accepted, so different valid inputs never decode to the same bytes.
+pub const fn fnv1a_hash_base64_64(encoded: &str) -> Result<u64, Base64Error> {
+    let bytes = encoded.as_bytes();
+    if !bytes.len().is_multiple_of(4) {
+        return Err(Base64Error::InvalidLength);
+    }
+
+    let mut hash = FNV_OFFSET_BASIS_64;
+
+    let mut i = 0;
+    while i < bytes.len() {
+        let last = i + 4 == bytes.len();
+        let padding = if last && bytes[i + 3] == b'=' {
+            if bytes[i + 2] == b'=' { 2 } else { 1 }
+        } else {
+            0
+        };
+
+        let mut group = 0u32;
+        let mut j = 0;
+        while j < 4 - padding {
+            group = group << 6 | match base64_value(bytes[i + j]) {
+                Ok(value) => value,
+                Err(error) => return Err(error),
+            };
+            j += 1;
+        }
+        group <<= 6 * padding as u32;
+        // Bits below the decoded bytes must be zero, so every input has a single encoding.
+        if group & ((1 << (8 * padding)) - 1) != 0 {
+            return Err(Base64Error::InvalidPadding);
+        }
+
+        let decoded = group.to_be_bytes();
+        hash = fnv1a_continue_64(hash, &[decoded[1], decoded[2], decoded[3]], 3 - padding, false);
+        i += 4;
+    }
+    Ok(hash)
+}
+
+
+/// Computes the 64-bits fnv1a hashes of four inputs, which may differ in length.
+/// The four states are advanced in lockstep, one byte per input per step, so their
+/// independent multiplies can overlap instead of waiting on a single dependency chain.
+/// The result always equals hashing each input with `fnv1a_hash_64_case_sensitive`.
+pub fn fnv1a_hash_64_batch4(inputs: [&[u8]; 4]) -> [u64; 4] {
+    let mut hashes = [FNV_OFFSET_BASIS_64; 4];
+
+    let common = inputs.iter().map(|input| input.len()).min().unwrap_or(0);
+    for i in 0..common {
+        for (hash, input) in hashes.iter_mut().zip(&inputs) {
+            *hash = (*hash ^ input[i] as u64).wrapping_mul(FNV_PRIME_64);
+        }
+    }
+    for (hash, input) in hashes.iter_mut().zip(&inputs) {
+        let tail = &input[common..];
+        *hash = fnv1a_continue_64(*hash, tail, tail.len(), false);
+    }
+    hashes
+}
+
+
+/// Writes the 32-bits fnv1a hash of each cols-byte row of matrix into out and returns the
+/// amount of rows. A final partial row is hashed with its actual length.
+/// Panics if cols is 0 or out is shorter than the amount of rows.
+pub fn fnv1a_hash_matrix_rows_32_into(matrix: &[u8], cols: usize, out: &mut [u32]) -> usize {
+    assert!(cols > 0, "matrix must have at least one column");
+    let rows = matrix.len().div_ceil(cols);
+    assert!(out.len() >= rows, "output has fewer slots than matrix rows");
+
+    for (slot, row) in out.iter_mut().zip(matrix.chunks(cols)) {
+        *slot = fnv1a_continue_32(FNV_OFFSET_BASIS_32, row, row.len(), false);
+    }
+    rows
+}
+
+/// Computes the 32-bits fnv1a hash of each cols-byte row of matrix. A final partial row is
+/// hashed with its actual length. Panics if cols is 0.
+#[cfg(feature = "alloc")]
+pub fn fnv1a_hash_matrix_rows_32(matrix: &[u8], cols: usize) -> alloc::vec::Vec<u32> {
+    let mut hashes = alloc::vec![0; if cols > 0 { matrix.len().div_ceil(cols) } else { 0 }];
+    fnv1a_hash_matrix_rows_32_into(matrix, cols, &mut hashes);
+    hashes
+}
+
+
+
+#[test]
+#[allow(clippy::char_lit_as_u8)]
+fn fnv1a_test_case_comparison() {
+    let bytes = ['A' as u8, 'B' as u8];
+    assert_eq!(fnv1a_hash_64(&bytes, None, false), fnv1a_hash_64(&bytes, None, true));
+    assert_eq!(fnv1a_hash_32(&bytes, None, false), fnv1a_hash_32(&bytes, None, true));
+
+    let bytes = ['a' as u8, 'B' as u8];
+    assert_ne!(fnv1a_hash_64(&bytes, None, false), fnv1a_hash_64(&bytes, None, true));
+    assert_ne!(fnv1a_hash_32(&bytes, None, false), fnv1a_hash_32(&bytes, None, true));
+
+    let bytes = ['a' as u8, 'B' as u8];
+    let comparison = ['A' as u8, 'B' as u8];
+    assert_eq!(fnv1a_hash_64(&bytes, None, true), fnv1a_hash_64(&comparison, None, true));
+    assert_eq!(fnv1a_hash_32(&bytes, None, true), fnv1a_hash_32(&comparison, None, true));
+
+
+}
+
+#[test]
+fn fnv1_test_vectors() {
+    assert_eq!(fnv1_hash_str_32(""), 0x811c9dc5);
+    assert_eq!(fnv1_hash_str_32("a"), 0x050c5d7e);
+    assert_eq!(fnv1_hash_str_32("foobar"), 0x31f0b262);
+    assert_eq!(fnv1_hash_str_64(""), 0xcbf29ce484222325);
+    assert_eq!(fnv1_hash_str_64("a"), 0xaf63bd4c8601b7be);
+    assert_eq!(fnv1_hash_str_64("foobar"), 0x340d8765a4dda9c2);
+
+    for input in ["a", "b", "ab", "foobar", "Hello, World!"].iter() {
+        assert_ne!(fnv1_hash_str_32(input), fnv1a_hash_str_32(input));
+        assert_ne!(fnv1_hash_str_64(input), fnv1a_hash_str_64(input));
+    }
+}
+
+#[test]
+fn fnv1a_test_vectors_128() {
+    assert_eq!(fnv1a_hash_str_128(""), 0x6c62272e07bb014262b821756295c58d);
+    assert_eq!(fnv1a_hash_str_128("a"), 0xd228cb696f1a8caf78912b704e4a8964);
+    assert_eq!(fnv1a_hash_str_128("foobar"), 0x343e1662793c64bf6f0d3597ba446f18);
+
+    let hash = fnv1a_hash_str_128("foobar");
+    assert_ne!(hash, fnv1a_hash_str_64("foobar") as u128);
+    assert_ne!(hash, fnv1a_hash_str_32("foobar") as u128);
+}
+
+#[test]
+fn fnv1a_test_8_xor() {
+    let basis = FNV_OFFSET_BASIS_32.to_ne_bytes();
+    assert_eq!(fnv1a_hash_str_8_xor(""), basis[0] ^ basis[1] ^ basis[2] ^ basis[3]);
+
+    let mut seen = [false; 256];
+    let corpus = ["a", "b", "c", "foo", "bar", "baz", "hello", "world"];
+    let mut distinct = 0;
+    for input in corpus.iter() {
+        let hash = fnv1a_hash_str_8_xor(input) as usize;
+        if !seen[hash] {
+            seen[hash] = true;
+            distinct += 1;
+        }
+    }
+    assert!(distinct > corpus.len() / 2);
+}
+
+#[test]
+fn fnv1a_test_str_case() {
+    assert_eq!(fnv1a_hash_str_case_64("Hello"), fnv1a_hash_str_case_64("hello"));
+    assert_ne!(fnv1a_hash_str_case_64("Hello"), fnv1a_hash_str_64("Hello"));
+    assert_eq!(fnv1a_hash_str_case_32("Hello"), fnv1a_hash_str_case_32("hello"));
+    assert_ne!(fnv1a_hash_str_case_32("Hello"), fnv1a_hash_str_32("Hello"));
+    assert_eq!(fnv1a_hash_str_16_xor_case("Hello"), fnv1a_hash_str_16_xor_case("hello"));
+}
+
+#[test]
+fn fnv1a_test_const_macros() {
+    assert_eq!(const_fnv1a_64!("foo"), fnv1a_hash_str_64("foo"));
+    assert_eq!(const_fnv1a_32!("foo"), fnv1a_hash_str_32("foo"));
+
+    const BAR: u64 = const_fnv1a_64!("bar");
+    let matched = match fnv1a_hash_str_64("bar") {
+        h if h == const_fnv1a_64!("foo") => 0,
+        BAR => 1,
+        _ => 2,
+    };
+    assert_eq!(matched, 1);
+}
+
+#[test]
+fn fnv1a_test_incremental_hasher() {
+    const SPLIT_64: u64 = Fnv1aHasher64::new().update(b"hello ").update(b"world").finalize();
+    assert_eq!(SPLIT_64, fnv1a_hash_str_64("hello world"));
+    let split_32 = Fnv1aHasher32::new().update(b"hello ").update(b"world").finalize();
+    assert_eq!(split_32, fnv1a_hash_str_32("hello world"));
+
+    let split_64 = Fnv1aHasher64Case::new().update(b"Hello ").update(b"World").finalize();
+    assert_eq!(split_64, fnv1a_hash_64_ascii_case_insensitive(b"hello world", None));
+    let split_32 = Fnv1aHasher32Case::new().update(b"Hello ").update(b"World").finalize();
+    assert_eq!(split_32, fnv1a_hash_32_ascii_case_insensitive(b"hello world", None));
+
+    assert_eq!(Fnv1aHasher64::default().finalize(), fnv1a_hash_str_64(""));
+}
+
+#[test]
+fn fnv1a_test_primitives() {
+    assert_ne!(fnv1a_hash_u32_64(0), fnv1a_hash_u32_64(1));
+    assert_eq!(fnv1a_hash_u32_64(0x01020304), fnv1a_hash_64(&[4, 3, 2, 1], None, false));
+    assert_eq!(fnv1a_hash_u16_32(0x0102), fnv1a_hash_32(&[2, 1], None, false));
+    assert_eq!(fnv1a_hash_u64_64(42), fnv1a_hash_64(&42u64.to_le_bytes(), None, false));
+    assert_eq!(fnv1a_hash_i32_64(-1), fnv1a_hash_u32_64(u32::MAX));
+    assert_eq!(fnv1a_hash_i8_32(-1), fnv1a_hash_u8_32(0xFF));
+}
+
+#[test]
+fn fnv1a_test_chain() {
+    let parts: [&[u8]; 2] = [b"foo", b"bar"];
+    assert_eq!(fnv1a_hash_64_chain(&parts, false), fnv1a_hash_64(b"foobar", None, false));
+    assert_eq!(fnv1a_hash_32_chain(&parts, false), fnv1a_hash_32(b"foobar", None, false));
+    assert_eq!(fnv1a_hash_64_chain(&parts, true), fnv1a_hash_64(b"foobar", None, true));
+    assert_eq!(fnv1a_hash_64_chain(&[], false), FNV_OFFSET_BASIS_64);
+    assert_eq!(fnv1a_hash_32_chain(&[b"foo"], false), fnv1a_hash_32(b"foo", None, false));
+}
+
+#[test]
+fn fnv1a_test_seeded() {
+    for input in ["", "a", "foo", "Hello, World!"].iter() {
+        let bytes = input.as_bytes();
+        assert_eq!(fnv1a_hash_64_seeded(bytes, None, false, FNV_OFFSET_BASIS_64), fnv1a_hash_64(bytes, None, false));
+        assert_eq!(fnv1a_hash_32_seeded(bytes, None, false, FNV_OFFSET_BASIS_32), fnv1a_hash_32(bytes, None, false));
+        assert_ne!(fnv1a_hash_64_seeded(bytes, None, false, 1), fnv1a_hash_64_seeded(bytes, None, false, 2));
+        assert_ne!(fnv1a_hash_32_seeded(bytes, None, false, 1), fnv1a_hash_32_seeded(bytes, None, false, 2));
+    }
+}
+
+#[test]
+fn fnv1a_test_pair() {
+    assert_ne!(fnv1a_hash_64_pair(b"ab", b"c"), fnv1a_hash_64_pair(b"a", b"bc"));
+    assert_ne!(fnv1a_hash_64_pair(b"ab", b"c"), fnv1a_hash_64_pair(b"c", b"ab"));
+
+    let parts: [&[u8]; 4] = [&1u64.to_le_bytes(), b"a", &2u64.to_le_bytes(), b"bc"];
+    assert_eq!(fnv1a_hash_64_pair(b"a", b"bc"), fnv1a_hash_64_chain(&parts, false));
+}
+
+#[test]
+fn fnv1a_test_fixed() {
+    assert_eq!(fnv1a_hash_fixed_64(&[]), fnv1a_hash_64(&[], None, false));
+    assert_eq!(fnv1a_hash_fixed_64(&[7]), fnv1a_hash_64(&[7], None, false));
+    assert_eq!(fnv1a_hash_fixed_64(&[0xAB; 16]), fnv1a_hash_64(&[0xAB; 16], None, false));
+    assert_eq!(fnv1a_hash_fixed_64(&[0xCD; 32]), fnv1a_hash_64(&[0xCD; 32], None, false));
+    assert_eq!(fnv1a_hash_fixed_32(&[]), fnv1a_hash_32(&[], None, false));
+    assert_eq!(fnv1a_hash_fixed_32(&[7]), fnv1a_hash_32(&[7], None, false));
+    assert_eq!(fnv1a_hash_fixed_32(&[0xAB; 16]), fnv1a_hash_32(&[0xAB; 16], None, false));
+    assert_eq!(fnv1a_hash_fixed_32(&[0xCD; 32]), fnv1a_hash_32(&[0xCD; 32], None, false));
+}
+
+#[test]
+fn fnv1a_test_16_xor_case() {
+    assert_eq!(fnv1a_hash_16_xor_case_insensitive(b"Hello", None), fnv1a_hash_16_xor_case_insensitive(b"hello", None));
+    assert_ne!(fnv1a_hash_16_xor_case_insensitive(b"hello", None), fnv1a_hash_16_xor(b"hello", None));
+    assert_eq!(fnv1a_hash_16_xor(b"hello", None), fnv1a_hash_str_16_xor("hello"));
+    assert_eq!(fnv1a_hash_16_xor_case_insensitive(b"hello", None), fnv1a_hash_str_16_xor_case("hello"));
+}
+
+#[test]
+fn fnv1a_test_str_concat() {
+    assert_eq!(fnv1a_hash_str_concat_64(&["a", "b", "c"]), fnv1a_hash_str_64("abc"));
+    assert_eq!(fnv1a_hash_str_concat_32(&["a", "b", "c"]), fnv1a_hash_str_32("abc"));
+    assert_eq!(fnv1a_hash_str_concat_case_64(&["A", "b", "C"]), fnv1a_hash_str_case_64("abc"));
+    assert_eq!(fnv1a_hash_str_concat_case_32(&["A", "b", "C"]), fnv1a_hash_str_case_32("abc"));
+    assert_eq!(fnv1a_hash_str_concat_64(&[]), FNV_OFFSET_BASIS_64);
+    assert_eq!(fnv1a_hash_str_concat_32(&[]), FNV_OFFSET_BASIS_32);
+}
+
+#[test]
+fn fnv1a_test_char() {
+    assert_eq!(fnv1a_hash_char_64('a'), fnv1a_hash_str_64("a"));
+    assert_eq!(fnv1a_hash_char_32('a'), fnv1a_hash_str_32("a"));
+    assert_ne!(fnv1a_hash_char_64('A'), fnv1a_hash_char_64('a'));
+    for c in ['é', '€', '😀'].iter() {
+        let mut buffer = [0u8; 4];
+        let encoded = c.encode_utf8(&mut buffer).as_bytes();
+        assert_eq!(fnv1a_hash_char_64(*c), fnv1a_hash_64(encoded, None, false));
+        assert_eq!(fnv1a_hash_char_32(*c), fnv1a_hash_32(encoded, None, false));
+    }
+}
+
+#[test]
+fn fnv1a_test_with_prefix() {
+    assert_ne!(fnv1a_hash_str_with_prefix_64("users", "42"), fnv1a_hash_str_with_prefix_64("groups", "42"));
+    assert_eq!(fnv1a_hash_str_with_prefix_64("users:", "42"), fnv1a_hash_str_64("users:42"));
+    assert_eq!(fnv1a_hash_with_prefix_64(b"", b"42"), fnv1a_hash_64(b"42", None, false));
+}
+
+#[test]
+fn fnv1a_test_skip() {
+    assert_eq!(fnv1a_hash_64_skip(b"abcd", 1, 0), fnv1a_hash_64(b"abcd", None, false));
+    assert_eq!(fnv1a_hash_64_skip(b"abcd", 2, 0), fnv1a_hash_64(b"ac", None, false));
+    assert_eq!(fnv1a_hash_32_skip(b"abcd", 2, 1), fnv1a_hash_32(b"bd", None, false));
+    assert_eq!(fnv1a_hash_64_skip(b"abcd", 0, 0), FNV_OFFSET_BASIS_64);
+    assert_eq!(fnv1a_hash_32_skip(b"abcd", 1, 4), FNV_OFFSET_BASIS_32);
+
+    const LARGE: u64 = fnv1a_hash_64_skip(b"ab", usize::MAX, 1);
+    assert_eq!(LARGE, fnv1a_hash_64(b"b", None, false));
+    assert_eq!(fnv1a_hash_64_skip(b"ab", usize::MAX, 0), fnv1a_hash_64(b"a", None, false));
+    assert_eq!(fnv1a_hash_32_skip(b"ab", usize::MAX, 1), fnv1a_hash_32(b"b", None, false));
+}
+
+#[test]
+fn fnv1a_test_case_latin1() {
+    assert_eq!(fnv1a_hash_case_latin1_64(b"\xC0", None), fnv1a_hash_case_latin1_64(b"\xE0", None));
+    assert_eq!(fnv1a_hash_case_latin1_64(b"\xDE", None), fnv1a_hash_case_latin1_64(b"\xFE", None));
+    assert_ne!(fnv1a_hash_case_latin1_64(b"\xD7", None), fnv1a_hash_case_latin1_64(b"\xF7", None));
+    assert_eq!(fnv1a_hash_case_latin1_64(b"Caf\xC9", None), fnv1a_hash_case_latin1_64(b"cAf\xE9", None));
+}
+
+#[test]
+fn fnv1a_test_reduce() {
+    for input in ["", "a", "foo", "bar", "Hello, World!"].iter() {
+        let bytes = input.as_bytes();
+        for n in [1u32, 3, 10, 1000, u32::MAX].iter() {
+            assert!(fnv1a_hash_32_reduce(bytes, *n) < *n);
+            assert!(fnv1a_hash_64_reduce(bytes, *n as u64) < *n as u64);
+        }
+        assert_eq!(fnv1a_hash_32_reduce(bytes, 16), fnv1a_hash_32(bytes, None, false) >> 28);
+        assert_eq!(fnv1a_hash_64_reduce(bytes, 16), fnv1a_hash_64(bytes, None, false) >> 60);
+    }
+}
+
+#[test]
+fn fnv1a_test_word_slices() {
+    assert_eq!(fnv1a_hash_u16_slice_64(&[0x0102]), fnv1a_hash_64(&[0x02, 0x01], None, false));
+    assert_eq!(fnv1a_hash_u32_slice_64(&[0x01020304]), fnv1a_hash_64(&[4, 3, 2, 1], None, false));
+    assert_eq!(fnv1a_hash_u64_slice_64(&[1, 2]), fnv1a_hash_64(&[1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0], None, false));
+    assert_eq!(fnv1a_hash_u16_slice_64(&[]), FNV_OFFSET_BASIS_64);
+    assert_eq!(fnv1a_hash_u32_slice_64(&[]), FNV_OFFSET_BASIS_64);
+}
+
+#[test]
+fn fnv1a_test_floats() {
+    assert_eq!(fnv1a_hash_f32_64(0.0), fnv1a_hash_f32_64(-0.0));
+    assert_eq!(fnv1a_hash_f64_64(0.0), fnv1a_hash_f64_64(-0.0));
+    assert_eq!(fnv1a_hash_f32_64(f32::NAN), fnv1a_hash_f32_64(f32::from_bits(0x7FC0_0001)));
+    assert_eq!(fnv1a_hash_f32_64(f32::NAN), fnv1a_hash_f32_64(-f32::NAN));
+    assert_eq!(fnv1a_hash_f64_64(f64::NAN), fnv1a_hash_f64_64(f64::from_bits(0xFFF8_0000_0000_0001)));
+    assert_eq!(fnv1a_hash_f64_64(1.5), fnv1a_hash_u64_64(1.5f64.to_bits()));
+
+    let corpus = [1.0, -1.0, 0.5, 2.0, f64::INFINITY, f64::NEG_INFINITY, f64::MIN_POSITIVE];
+    for (i, a) in corpus.iter().enumerate() {
+        for b in corpus[i + 1..].iter() {
+            assert_ne!(fnv1a_hash_f64_64(*a), fnv1a_hash_f64_64(*b));
+            assert_ne!(fnv1a_hash_f32_64(*a as f32), fnv1a_hash_f32_64(*b as f32));
+        }
+    }
+}
+
+#[test]
+fn fnv1a_test_str_limit() {
+    assert_eq!(fnv1a_hash_str_64_limit("hello", 3), fnv1a_hash_str_64("hel"));
+    assert_eq!(fnv1a_hash_str_32_limit("hello", 3), fnv1a_hash_str_32("hel"));
+    assert_eq!(fnv1a_hash_str_64_limit("hello", 0), FNV_OFFSET_BASIS_64);
+    assert_eq!(fnv1a_hash_str_32_limit("hello", 0), FNV_OFFSET_BASIS_32);
+    assert_eq!(fnv1a_hash_str_64_limit("hello", 10), fnv1a_hash_str_64("hello"));
+    assert_eq!(fnv1a_hash_str_case_64_limit("HELlo", 3), fnv1a_hash_str_case_64("hel"));
+    assert_eq!(fnv1a_hash_str_case_32_limit("HELlo", 3), fnv1a_hash_str_case_32("hel"));
+}
+
+#[test]
+fn fnv1a_test_str_char_limit() {
+    assert_eq!(fnv1a_hash_str_64_char_limit("hello", 3), fnv1a_hash_str_64_limit("hello", 3));
+    assert_eq!(fnv1a_hash_str_32_char_limit("hello", 3), fnv1a_hash_str_32_limit("hello", 3));
+    assert_eq!(fnv1a_hash_str_64_char_limit("héllo", 2), fnv1a_hash_str_64("hé"));
+    assert_eq!(fnv1a_hash_str_32_char_limit("h😀llo", 2), fnv1a_hash_str_32("h😀"));
+    assert_eq!(fnv1a_hash_str_64_char_limit("héllo", 10), fnv1a_hash_str_64("héllo"));
+    assert_eq!(fnv1a_hash_str_64_char_limit("héllo", 0), FNV_OFFSET_BASIS_64);
+}
+
+#[test]
+fn fnv1a_test_64_folds() {
+    let hash = fnv1a_hash_64(b"foobar", None, false);
+    assert_eq!(fnv1a_hash_64_xor_fold(b"foobar", None, false), 0x85944171 ^ 0xf73967e8);
+    assert_eq!(fnv1a_hash_64_and_fold(b"foobar", None, false), ((hash >> 32) & hash) as u32);
+    assert_ne!(fnv1a_hash_64_xor_fold(b"foobar", None, false), fnv1a_hash_32(b"foobar", None, false));
+}
+
+#[test]
+fn fnv1a_test_static_map() {
+    let keys = ["zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine"];
+    let mut entries = [("", 0); 10];
+    for (i, key) in keys.iter().enumerate() {
+        entries[i] = (*key, i);
+    }
+
+    // A map without spare slots forces every collision to be probed.
+    let full: StaticMap<&str, usize, 10> = StaticMap::new(entries);
+    let sparse: StaticMap<&str, usize, 32> = StaticMap::new(entries);
+    for (i, key) in keys.iter().enumerate() {
+        assert_eq!(full.get(key), Some(&i));
+        assert_eq!(sparse.get(key), Some(&i));
+    }
+    assert_eq!(full.get(&"ten"), None);
+    assert_eq!(sparse.get(&"ten"), None);
+
+    let bytes: StaticMap<&[u8], u8, 4> = StaticMap::new([(&b"a"[..], 1), (&b"a"[..], 2)]);
+    assert_eq!(bytes.get(&&b"a"[..]), Some(&2));
+}
+
+#[test]
+fn fnv1a_test_const_str_set() {
+    const_str_set!(KEYWORDS = ["if", "else", "while", "for"]);
+    const _: () = assert!(KEYWORDS.contains("for"));
+    for keyword in ["if", "else", "while", "for"].iter() {
+        assert!(KEYWORDS.contains(keyword));
+    }
+    assert!(!KEYWORDS.contains("loop"));
+    assert!(!KEYWORDS.contains("If"));
+    assert!(!KEYWORDS.contains(""));
+
+    const_str_set!(EMPTY = []);
+    assert!(!EMPTY.contains(""));
+}
+
+#[test]
+fn fnv1a_test_cstr() {
+    let hello = core::ffi::CStr::from_bytes_with_nul(b"hello\0").unwrap();
+    assert_eq!(fnv1a_hash_cstr_64(hello), fnv1a_hash_str_64("hello"));
+    assert_eq!(fnv1a_hash_cstr_32(hello), fnv1a_hash_str_32("hello"));
+    assert_eq!(fnv1a_hash_nul_terminated_64(b"hello\0world"), fnv1a_hash_str_64("hello"));
+    assert_eq!(fnv1a_hash_nul_terminated_32(b"hello"), fnv1a_hash_str_32("hello"));
+}
+
+#[test]
+fn fnv1a_test_combine() {
+    let (a, b) = (fnv1a_hash_str_64("a"), fnv1a_hash_str_64("b"));
+    assert_ne!(fnv1a_hash_combine_64(a, b), fnv1a_hash_combine_64(b, a));
+    assert_ne!(fnv1a_hash_combine_64(a, a), 0);
+    assert_eq!(fnv1a_hash_combine_64(a, b), fnv1a_hash_64_seeded(&b.to_le_bytes(), None, false, a));
+
+    let (a, b) = (fnv1a_hash_str_32("a"), fnv1a_hash_str_32("b"));
+    assert_ne!(fnv1a_hash_combine_32(a, b), fnv1a_hash_combine_32(b, a));
+    assert_eq!(fnv1a_hash_combine_32(a, b), fnv1a_hash_32_seeded(&b.to_le_bytes(), None, false, a));
+}
+
+#[test]
+fn fnv1a_test_const_hash_trait() {
+    fn two_hash<T: ConstHash>(a: T, b: T) -> (u64, u64) {
+        (a.hash_64(), b.hash_64())
+    }
+
+    assert_eq!(two_hash(1u8, 2u8), (fnv1a_hash_u8_64(1), fnv1a_hash_u8_64(2)));
+    assert_eq!(two_hash(1u16, 2u16), (fnv1a_hash_u16_64(1), fnv1a_hash_u16_64(2)));
+    assert_eq!(two_hash(1u32, 2u32), (fnv1a_hash_u32_64(1), fnv1a_hash_u32_64(2)));
+    assert_eq!(two_hash(1u64, 2u64), (fnv1a_hash_u64_64(1), fnv1a_hash_u64_64(2)));
+    assert_eq!(two_hash(1u128, 2u128), (fnv1a_hash_u128_64(1), fnv1a_hash_u128_64(2)));
+    assert_eq!(two_hash(1usize, 2usize), (fnv1a_hash_usize_64(1), fnv1a_hash_usize_64(2)));
+    assert_eq!(two_hash(-1i8, 2i8), (fnv1a_hash_i8_64(-1), fnv1a_hash_i8_64(2)));
+    assert_eq!(two_hash(-1i16, 2i16), (fnv1a_hash_i16_64(-1), fnv1a_hash_i16_64(2)));
+    assert_eq!(two_hash(-1i32, 2i32), (fnv1a_hash_i32_64(-1), fnv1a_hash_i32_64(2)));
+    assert_eq!(two_hash(-1i64, 2i64), (fnv1a_hash_i64_64(-1), fnv1a_hash_i64_64(2)));
+    assert_eq!(two_hash(-1i128, 2i128), (fnv1a_hash_i128_64(-1), fnv1a_hash_i128_64(2)));
+    assert_eq!(two_hash(-1isize, 2isize), (fnv1a_hash_isize_64(-1), fnv1a_hash_isize_64(2)));
+    assert_eq!(two_hash(false, true), (fnv1a_hash_u8_64(0), fnv1a_hash_u8_64(1)));
+    assert_eq!(two_hash('a', 'é'), (fnv1a_hash_char_64('a'), fnv1a_hash_char_64('é')));
+    assert_eq!(two_hash("a", "b"), (fnv1a_hash_str_64("a"), fnv1a_hash_str_64("b")));
+    assert_eq!(two_hash(&b"a"[..], &b"b"[..]), (fnv1a_hash_str_64("a"), fnv1a_hash_str_64("b")));
+    assert_eq!("a".hash_32(), fnv1a_hash_str_32("a"));
+    assert_eq!(7u32.hash_32(), fnv1a_hash_u32_32(7));
+}
+
+#[test]
+fn fnv1a_test_rolling_hasher() {
+    let mut hasher = FnvRollingHasher32::<3>::new();
+    let hashes = [b'a', b'b', b'c', b'd', b'e'].map(|byte| hasher.push_byte(byte));
+    assert_eq!(hashes[0], fnv1a_hash_32(b"a", None, false));
+    assert_eq!(hashes[1], fnv1a_hash_32(b"ab", None, false));
+    assert_eq!(hashes[2], fnv1a_hash_32(b"abc", None, false));
+    assert_eq!(hashes[3], fnv1a_hash_32(b"bcd", None, false));
+    assert_eq!(hashes[4], fnv1a_hash_32(b"cde", None, false));
+}
+
+#[test]
+fn fnv1a_test_word_slices_be() {
+    assert_eq!(fnv1a_hash_u16_slice_be_64(&[0x0102]), fnv1a_hash_64(&[0x01, 0x02], None, false));
+    assert_eq!(fnv1a_hash_u32_slice_be_64(&[0x01020304]), fnv1a_hash_64(&[1, 2, 3, 4], None, false));
+    assert_eq!(fnv1a_hash_u64_slice_be_64(&[1]), fnv1a_hash_64(&1u64.to_be_bytes(), None, false));
+    assert_ne!(fnv1a_hash_u32_slice_be_64(&[1, 2]), fnv1a_hash_u32_slice_64(&[1, 2]));
+    assert_eq!(fnv1a_hash_u32_slice_be_64(&[0, 0]), fnv1a_hash_u32_slice_64(&[0, 0]));
+}
+
+#[test]
+fn fnv1a_test_verify() {
+    const _: () = assert!(fnv1a_hash_verify_64(b"foobar", 0x85944171f73967e8));
+    assert!(fnv1a_hash_verify_32(b"foobar", 0xbf9cf968));
+
+    let mut bytes = *b"foobar";
+    for i in 0..bytes.len() * 8 {
+        bytes[i / 8] ^= 1 << (i % 8);
+        assert!(!fnv1a_hash_verify_64(&bytes, 0x85944171f73967e8));
+        assert!(!fnv1a_hash_verify_32(&bytes, 0xbf9cf968));
+        bytes[i / 8] ^= 1 << (i % 8);
+    }
+}
+
+#[test]
+fn fnv1a_test_nonzero() {
+    use core::num::{NonZeroU32, NonZeroU64, NonZeroUsize};
+
+    assert_ne!(fnv1a_hash_nonzero_u32_64(NonZeroU32::new(1).unwrap()), fnv1a_hash_u32_64(1));
+    assert_ne!(fnv1a_hash_nonzero_u64_64(NonZeroU64::new(1).unwrap()), fnv1a_hash_u64_64(1));
+    assert_ne!(fnv1a_hash_nonzero_usize_64(NonZeroUsize::new(1).unwrap()), fnv1a_hash_usize_64(1));
+    assert_eq!(fnv1a_hash_nonzero_u32_64(NonZeroU32::new(2).unwrap()), fnv1a_hash_64(&[1, 2, 0, 0, 0], None, false));
+}
+
+#[test]
+fn fnv1a_test_str_split() {
+    let hash = fnv1a_hash_str_split_64("/a/b", b'/');
+    assert_ne!(hash, fnv1a_hash_str_split_64("/ab/", b'/'));
+    assert_ne!(hash, fnv1a_hash_str_split_64("/a/b/", b'/'));
+    assert_ne!(hash, fnv1a_hash_str_split_64("/a/b", b'.'));
+
+    let parts: [&[u8]; 6] = [&0u64.to_le_bytes(), b"", &1u64.to_le_bytes(), b"a", &1u64.to_le_bytes(), b"b"];
+    assert_eq!(hash, fnv1a_hash_64_chain(&parts, false));
+}
+
+#[test]
+fn fnv1a_test_64_halves() {
+    assert_eq!(fnv1a_hash_64_high32(b"foobar"), 0x85944171);
+    assert_eq!(fnv1a_hash_64_low32(b"foobar"), 0xf73967e8);
+    assert_eq!(fnv1a_hash_64_to_u32(b"foobar"), 0x85944171 ^ 0xf73967e8);
+}
+
+#[test]
+fn fnv1a_test_perfect_hash() {
+    const KEYS: [&str; 8] = ["alpha", "beta", "gamma", "delta", "epsilon", "zeta", "eta", "theta"];
+    const TABLE: PerfectHashTable<8> = build_perfect_hash(&KEYS);
+
+    for (i, key) in KEYS.iter().enumerate() {
+        assert_eq!(TABLE.get(key), Some(i));
+    }
+    assert_eq!(TABLE.get("iota"), None);
+    assert_eq!(TABLE.get(""), None);
+    assert_eq!(build_perfect_hash(&[]).get("alpha"), None);
+}
+
+#[test]
+fn fnv1a_test_option() {
+    assert_ne!(fnv1a_hash_option_64(None), fnv1a_hash_option_64(Some(b"")));
+    assert_eq!(fnv1a_hash_option_64(None), fnv1a_hash_64(&[0xFF], None, false));
+    assert_eq!(fnv1a_hash_option_64(Some(b"abc")), fnv1a_hash_64(b"\x00abc", None, false));
+    assert_eq!(fnv1a_hash_option_str_64(Some("abc")), fnv1a_hash_option_64(Some(b"abc")));
+    assert_eq!(fnv1a_hash_option_str_64(None), fnv1a_hash_option_64(None));
+}
+
+#[test]
+fn fnv1a_test_from_offset() {
+    let bytes = b"hello world";
+    for start in 0..bytes.len() {
+        for end in start..=bytes.len() {
+            assert_eq!(fnv1a_hash_64_from_offset(bytes, start, end), fnv1a_hash_64(&bytes[start..end], None, false));
+            assert_eq!(fnv1a_hash_32_from_offset(bytes, start, end), fnv1a_hash_32(&bytes[start..end], None, false));
+        }
+    }
+    assert_eq!(fnv1a_hash_64_from_offset(bytes, 5, 2), FNV_OFFSET_BASIS_64);
+    assert_eq!(fnv1a_hash_32_from_offset(bytes, 20, 30), FNV_OFFSET_BASIS_32);
+}
+
+#[test]
+fn fnv1a_test_alternating() {
+    assert_eq!(fnv1a_hash_64_alternating(b"a").1, FNV_OFFSET_BASIS_64);
+    let (even, odd) = fnv1a_hash_64_alternating(b"abcd");
+    assert_eq!(even, fnv1a_hash_64_skip(b"abcd", 2, 0));
+    assert_eq!(odd, fnv1a_hash_64_skip(b"abcd", 2, 1));
+    assert_eq!(fnv1a_hash_64_alternating_combined(b"abcd"), even ^ odd);
+}
+
+#[test]
+fn fnv1a_test_str_to_rgb() {
+    const COLOR: (u8, u8, u8) = fnv1a_hash_str_to_rgb("main");
+    assert_eq!(COLOR, fnv1a_hash_str_to_rgb("main"));
+    assert_ne!(fnv1a_hash_str_to_rgb("main"), fnv1a_hash_str_to_rgb("worker"));
+
+    let (r, g, b) = fnv1a_hash_str_to_rgb("");
+    assert_eq!((r, g, b), (0xc5 | 0x40, 0x9d | 0x40, 0x1c | 0x40));
+}
+
+#[test]
+fn fnv1a_test_bytes_at() {
+    let bytes = *b"foobar";
+    let hash = unsafe { fnv1a_hash_64_bytes_at(bytes.as_ptr(), bytes.len()) };
+    assert_eq!(hash, fnv1a_hash_64(&bytes, None, false));
+}
+
+#[test]
+fn fnv1a_test_const_intern() {
+    const_intern!(STRINGS = ["alpha", "beta", "gamma"]);
+    assert_eq!(STRINGS.intern("alpha"), Some(0));
+    assert_eq!(STRINGS.unintern(0), "alpha");
+    assert_eq!(STRINGS.intern("gamma"), Some(2));
+    assert_eq!(STRINGS.intern("delta"), None);
+    assert_eq!(STRINGS.hashes()[1], fnv1a_hash_str_64("beta"));
+}
+
+#[test]
+fn fnv1a_test_with_salt() {
+    let data = b"payload";
+    let salt = b"request-1";
+    assert_eq!(fnv1a_hash_32_with_salt(data, salt), fnv1a_hash_32_chain(&[salt, data], false));
+    assert_ne!(fnv1a_hash_32_with_salt(data, salt), fnv1a_hash_32_with_salt(data, b"request-2"));
+    assert_eq!(fnv1a_hash_32_with_salt(data, b""), fnv1a_hash_32(data, None, false));
+}
+
+#[test]
+fn fnv1a_test_sorted_bytes() {
+    assert_eq!(fnv1a_hash_64_sorted_bytes(b"cba"), fnv1a_hash_64_sorted_bytes(b"abc"));
+    assert_eq!(fnv1a_hash_64_sorted_bytes(b"cba"), fnv1a_hash_64(b"abc", None, false));
+    assert_ne!(fnv1a_hash_64_sorted_bytes(b"aab"), fnv1a_hash_64_sorted_bytes(b"abb"));
+
+    let mut long = [0u8; 300];
+    for (i, byte) in long.iter_mut().enumerate() {
+        *byte = (i * 7) as u8;
+    }
+    let forward = fnv1a_hash_64_sorted_bytes(&long);
+    long.reverse();
+    assert_eq!(fnv1a_hash_64_sorted_bytes(&long), forward);
+}
+
+#[cfg(feature = "unicode")]
+#[test]
+fn fnv1a_test_str_normalized() {
+    assert_eq!(fnv1a_hash_str_64_normalized("\u{e9}"), fnv1a_hash_str_64_normalized("e\u{301}"));
+    assert_eq!(fnv1a_hash_str_64_normalized("caf\u{e9}"), fnv1a_hash_str_64("caf\u{e9}"));
+    assert_eq!(fnv1a_hash_str_64_normalized("hello"), fnv1a_hash_str_64("hello"));
+}
+
+#[test]
+fn fnv1a_test_nonzero_output() {
+    let zero_64 = [0xd5, 0x6b, 0xb9, 0x53, 0x42, 0x87, 0x08, 0x36];
+    let zero_32 = [0xcc, 0x24, 0x31, 0xc4];
+    assert_eq!(fnv1a_hash_64(&zero_64, None, false), 0);
+    assert_eq!(fnv1a_hash_32(&zero_32, None, false), 0);
+    assert_eq!(fnv1a_hash_64_nonzero(&zero_64).get(), 1);
+    assert_eq!(fnv1a_hash_32_nonzero(&zero_32).get(), 1);
+
+    assert_eq!(fnv1a_hash_64_nonzero(b"").get(), FNV_OFFSET_BASIS_64);
+    assert_eq!(fnv1a_hash_64_nonzero(b"hello").get(), fnv1a_hash_64(b"hello", None, false));
+    assert_eq!(fnv1a_hash_32_nonzero(b"hello").get(), fnv1a_hash_32(b"hello", None, false));
+}
+
+#[test]
+fn fnv1a_test_as_f64() {
+    const EMPTY: f64 = fnv1a_hash_64_as_f64(b"");
+    assert_eq!(EMPTY, 0.7966707284832713);
+    assert_ne!(fnv1a_hash_64_as_f64(b"hello"), EMPTY);
+
+    for i in 0..=255u8 {
+        let value = fnv1a_hash_64_as_f64(&[i]);
+        assert!((0.0..1.0).contains(&value));
+    }
+    assert!((0.0..1.0).contains(&fnv1a_hash_64_as_f64(&[0xd5, 0x6b, 0xb9, 0x53, 0x42, 0x87, 0x08, 0x36])));
+}
+
+#[test]
+fn fnv1a_test_byte_tables() {
+    assert_eq!(FNV1A_BYTE_TABLE_64[b'A' as usize], fnv1a_hash_64(b"A", None, false));
+    for b in 0..=255u8 {
+        assert_eq!(FNV1A_BYTE_TABLE_32[b as usize], fnv1a_hash_32(&[b], None, false));
+        assert_eq!(FNV1A_BYTE_TABLE_64[b as usize], fnv1a_hash_64(&[b], None, false));
+    }
+}
+
+#[test]
+fn fnv1a_test_length_separated() {
+    let split = fnv1a_hash_64_length_separated(&[b"a", b"b"]);
+    assert_ne!(split, fnv1a_hash_64_length_separated(&[b"ab", b""]));
+    assert_ne!(split, fnv1a_hash_64_length_separated(&[b"", b"ab"]));
+    assert_eq!(split, fnv1a_hash_64_pair(b"a", b"b"));
+    assert_eq!(fnv1a_hash_64_length_separated(&[]), FNV_OFFSET_BASIS_64);
+    assert_eq!(
+        fnv1a_hash_64_length_separated(&[b"hello"]),
+        fnv1a_hash_64_chain(&[&5u64.to_le_bytes(), b"hello"], false)
+    );
+}
+
+#[test]
+fn fnv1a_test_dispatch() {
+    #[derive(Debug, PartialEq)]
+    enum Word {
+        Costarring,
+        Liquid,
+        Get,
+        Unknown,
+    }
+
+    fn parse(input: &str) -> Word {
+        fnv1a_dispatch!(input => {
+            "costarring" => Word::Costarring,
+            "liquid" => Word::Liquid,
+            "get" => Word::Get,
+            _ => Word::Unknown,
+        })
+    }
+
+    assert_eq!(fnv1a_hash_str_32("costarring"), fnv1a_hash_str_32("liquid"));
+    assert_eq!(parse("costarring"), Word::Costarring);
+    assert_eq!(parse("liquid"), Word::Liquid);
+    assert_eq!(parse("get"), Word::Get);
+    assert_eq!(parse("post"), Word::Unknown);
+}
+
+#[test]
+fn fnv1a_test_mask_bits() {
+    let data = b"cuckoo";
+    let hash = fnv1a_hash_64(data, None, false);
+    assert_eq!(fnv1a_hash_64_bits(data, 32), hash & 0xFFFF_FFFF_u64);
+    assert_eq!(fnv1a_hash_64_bits(data, 64), hash);
+    assert_eq!(fnv1a_hash_64_bits(data, 0), 0);
+    assert_eq!(fnv1a_hash_64_mask(data, 0), 0);
+    assert_eq!(fnv1a_hash_64_mask(data, 0xF0F0), hash & 0xF0F0);
+}
+
+#[test]
+fn fnv1a_test_ascii_lower() {
+    assert_eq!(fnv1a_hash_str_64_ascii_lower("HELLO"), fnv1a_hash_str_64("hello"));
+    assert_eq!(fnv1a_hash_str_32_ascii_lower("HeLLo"), fnv1a_hash_str_32("hello"));
+    assert_eq!(fnv1a_hash_str_64_ascii_lower("A-1 [X]_\u{c9}"), fnv1a_hash_str_64("a-1 [x]_\u{c9}"));
+    assert_eq!(fnv1a_hash_str_64_ascii_lower("@[`{"), fnv1a_hash_str_64("@[`{"));
+
+    // The fold_to_upper path folds lowercase letters to uppercase.
+    assert_eq!(fnv1a_hash_str_case_64("hello"), fnv1a_hash_str_64("HELLO"));
+}
+
+#[test]
+fn fnv1a_test_fold_to_upper_legacy() {
+    // The case bit is cleared on every byte that has it set, as in 1.0.
+    assert_eq!(fnv1a_hash_64(b"1", None, true), fnv1a_hash_64(b"\x11", None, false));
+    assert_eq!(fnv1a_hash_str_case_64("1"), fnv1a_hash_str_case_64("\x11"));
+    assert_eq!(fnv1a_hash_32(b"a b", None, true), fnv1a_hash_32(b"A\0B", None, false));
+    assert_eq!(fnv1a_hash_64_case_insensitive(b"\xe9", None), fnv1a_hash_64_case_sensitive(b"\xc9", None));
+    assert_eq!(fnv1a_hash_32_case_insensitive(b"@[`{", None), fnv1a_hash_32_case_sensitive(b"@[@[", None));
+}
+
+#[test]
+fn fnv1a_test_ascii_case_insensitive() {
+    let input = "Key-01 [x]_\u{e9}@`{";
+    assert_eq!(fnv1a_hash_64_ascii_case_insensitive(input.as_bytes(), None), fnv1a_hash_str_64("KEY-01 [X]_\u{e9}@`{"));
+    assert_eq!(fnv1a_hash_32_ascii_case_insensitive(input.as_bytes(), None), fnv1a_hash_str_32("KEY-01 [X]_\u{e9}@`{"));
+    assert_eq!(fnv1a_hash_64_ascii_case_insensitive(b"hello", Some(2)), fnv1a_hash_64_case_sensitive(b"HE", None));
+    assert_ne!(fnv1a_hash_64_ascii_case_insensitive(b"1", None), fnv1a_hash_64_ascii_case_insensitive(b"\x11", None));
+}
+
+#[test]
+fn fnv1a_test_case_helpers_fold_letters_only() {
+    let input = "a-1 [x]_\u{e9}";
+    let bytes = input.as_bytes();
+    let expected_64 = fnv1a_hash_64_ascii_case_insensitive(bytes, None);
+    let expected_32 = fnv1a_hash_32_ascii_case_insensitive(bytes, None);
+
+    // Only the 1.0 compatible functions clear the case bit of digits, spaces and UTF-8 bytes.
+    assert_ne!(fnv1a_hash_64_case_insensitive(bytes, None), expected_64);
+    assert_eq!(fnv1a_hash_64_case_range(b"a-1", 0, 3), fnv1a_hash_64_ascii_case_insensitive(b"a-1", None));
+    assert_ne!(fnv1a_hash_64_case_range(b"a-1", 0, 3), fnv1a_hash_64_case_insensitive(b"a-1", None));
+
+    assert_eq!(fnv1a_hash_str_concat_case_64(&["a-1 ", "[x]_\u{e9}"]), expected_64);
+    assert_eq!(fnv1a_hash_str_concat_case_32(&["a-1 ", "[x]_\u{e9}"]), expected_32);
+    assert_eq!(fnv1a_hash_64_from_iter(bytes.iter().copied(), true), expected_64);
+    assert_eq!(fnv1a_hash_32_and_64(bytes, None, true), (expected_32, expected_64));
+    assert_eq!(fnv1a_hash_32_debug(bytes, None, true), (expected_32, 2));
+    assert_eq!(fnv1a_hash_64_chain(&[b"a-1", b" [x]_\xc3\xa9"], true), expected_64);
+    assert_eq!(fnv1a_hash_32_seeded(bytes, None, true, FNV_OFFSET_BASIS_32), expected_32);
+    assert_eq!(fnv1a_hash_str_case_64_limit(input, 64), expected_64);
+    assert_eq!(Fnv1aHasher64Case::new().update(bytes).finalize(), expected_64);
+    assert_eq!(fnv1a_hash_str_pair_case_64("a-1", "x"), fnv1a_hash_str_pair_64("A-1", "X"));
+}
+
+#[test]
+fn fnv1a_test_chunks() {
+    let mut chunks = fnv1a_hash_64_chunks(b"abcdef", 2);
+    assert_eq!(chunks.len(), 3);
+    assert_eq!(chunks.next(), Some(fnv1a_hash_64(b"ab", None, false)));
+    assert_eq!(chunks.next(), Some(fnv1a_hash_64(b"cd", None, false)));
+    assert_eq!(chunks.next(), Some(fnv1a_hash_64(b"ef", None, false)));
+    assert_eq!(chunks.next(), None);
+
+    let mut chunks = fnv1a_hash_64_chunks(b"abcde", 4);
+    assert_eq!(chunks.next(), Some(fnv1a_hash_64(b"abcd", None, false)));
+    assert_eq!(chunks.next(), Some(fnv1a_hash_64(b"e", None, false)));
+    assert_eq!(chunks.next(), None);
+    assert_eq!(fnv1a_hash_64_chunks(b"", 4).next(), None);
+}
+
+#[test]
+#[should_panic(expected = "chunk_size must be non-zero")]
+fn fnv1a_test_chunks_zero_size() {
+    fnv1a_hash_64_chunks(b"abc", 0);
+}
+
+#[test]
+fn fnv1a_test_pack() {
+    assert_eq!(fnv1a_hash_pack4_32(1, 2, 3, 4), fnv1a_hash_32(&[1, 2, 3, 4], None, false));
+    assert_eq!(fnv1a_hash_pack2_32(b'A', b'B'), fnv1a_hash_str_32("AB"));
+    assert_eq!(fnv1a_hash_pack8_32(1, 2, 3, 4, 5, 6, 7, 8), fnv1a_hash_32(&[1, 2, 3, 4, 5, 6, 7, 8], None, false));
+}
+
+#[test]
+fn fnv1a_test_with_nonce() {
+    assert_eq!(fnv1a_hash_64_with_nonce(0), fnv1a_hash_u64_64(0));
+    assert_eq!(fnv1a_hash_64_with_nonce(FNV_OFFSET_BASIS_64), 0xf4ed18ebf16aa5cc);
+    for nonce in 0..256 {
+        let diff = fnv1a_hash_64_with_nonce(nonce) ^ fnv1a_hash_64_with_nonce(nonce + 1);
+        assert!(diff.count_ones() >= 16);
+    }
+}
+
+#[test]
+fn fnv1a_test_ascii_only() {
+    const HASH: u32 = fnv1a_hash_str_32_ascii_only("hello");
+    assert_eq!(HASH, fnv1a_hash_str_32("hello"));
+    assert_eq!(fnv1a_hash_str_32_try_ascii("hello"), Some(HASH));
+    assert_eq!(fnv1a_hash_str_32_try_ascii("caf\u{e9}"), None);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn fnv1a_test_keystream() {
+    let data = b"attack at dawn, or maybe a bit later";
+    for key in [&b""[..], b"k", b"a longer key"].iter() {
+        let encrypted = FnvKeystream::encrypt(key, data);
+        assert_ne!(&encrypted[..], &data[..]);
+        assert_eq!(FnvKeystream::decrypt(key, &encrypted), &data[..]);
+    }
+    assert_ne!(FnvKeystream::encrypt(b"a", data), FnvKeystream::encrypt(b"b", data));
+}
+
+#[test]
+fn fnv1a_test_keystream_feedback() {
+    let mut keystream = FnvKeystream::new(b"key");
+    let mut state = fnv1a_hash_str_64("key");
+    let mut without_feedback = state;
+
+    let mut differs = false;
+    for _ in 0..32 {
+        let byte = keystream.next_byte();
+        assert_eq!(byte, (state >> 56) as u8);
+        state = fnv1a_step_64(state, byte);
+
+        differs |= byte != (without_feedback >> 56) as u8;
+        without_feedback = without_feedback.wrapping_mul(FNV_PRIME_64);
+    }
+    assert!(differs);
+}
+
+#[test]
+fn fnv1a_test_str_words() {
+    assert_eq!(fnv1a_hash_str_64_words("foo bar"), fnv1a_hash_str_64_words("bar foo"));
+    assert_eq!(fnv1a_hash_str_64_words(" foo\tbar\n"), fnv1a_hash_str_64_words("bar foo"));
+    assert_ne!(fnv1a_hash_str_64_words("foo bar"), fnv1a_hash_str_64_words("foo baz"));
+    assert_eq!(fnv1a_hash_str_64_words("foo"), fnv1a_hash_u64_64(fnv1a_hash_str_64("foo")));
+    assert_eq!(fnv1a_hash_str_64_words(""), FNV_OFFSET_BASIS_64);
+    assert_eq!(fnv1a_hash_str_64_words(" \t "), FNV_OFFSET_BASIS_64);
+}
+
+#[test]
+fn fnv1a_test_ratio() {
+    for input in [&b""[..], b"a", b"ratio", b"count-min sketch"].iter() {
+        let (numerator, denominator) = fnv1a_hash_64_ratio(input);
+        assert_eq!(denominator, u64::MAX);
+        assert!(numerator <= denominator);
+        assert_eq!(numerator, fnv1a_hash_64(input, None, false));
+    }
+}
+
+#[test]
+fn fnv1a_test_ip_addr() {
+    use core::net::{Ipv4Addr, Ipv6Addr};
+
+    assert_eq!(fnv1a_hash_ipv4_64(Ipv4Addr::new(127, 0, 0, 1)), fnv1a_hash_64(&[127, 0, 0, 1], None, false));
+    assert_ne!(fnv1a_hash_ipv4_64(Ipv4Addr::new(127, 0, 0, 1)), fnv1a_hash_ipv4_64(Ipv4Addr::new(10, 0, 0, 1)));
+    assert_eq!(fnv1a_hash_ipv6_64(Ipv6Addr::LOCALHOST), fnv1a_hash_64(&Ipv6Addr::LOCALHOST.octets(), None, false));
+    assert_ne!(fnv1a_hash_ipv6_64(Ipv6Addr::LOCALHOST), fnv1a_hash_ipv6_64(Ipv6Addr::UNSPECIFIED));
+}
+
+#[test]
+fn fnv1a_test_str_prefixes() {
+    let input = "trie";
+    let mut prefixes = fnv1a_hash_str_prefixes_32(input);
+    assert_eq!(prefixes.len(), 4);
+    assert_eq!(prefixes.next(), Some(fnv1a_hash_32(&[input.as_bytes()[0]], None, false)));
+    assert_eq!(prefixes.next(), Some(fnv1a_hash_str_32("tr")));
+    assert_eq!(prefixes.next(), Some(fnv1a_hash_str_32("tri")));
+    assert_eq!(prefixes.next(), Some(fnv1a_hash_str_32(input)));
+    assert_eq!(prefixes.next(), None);
+    assert_eq!(fnv1a_hash_str_prefixes_32("").next(), None);
+}
+
+#[test]
+fn fnv1a_test_batch() {
+    let inputs: [&[u8]; 3] = [b"", b"a", b"batch"];
+    let mut outputs = [0; 4];
+    fnv1a_hash_64_batch(&inputs, &mut outputs);
+    for (input, output) in inputs.iter().zip(&outputs) {
+        assert_eq!(*output, fnv1a_hash_64(input, None, false));
+    }
+    assert_eq!(outputs[3], 0);
+
+    fnv1a_hash_64_batch(&[], &mut []);
+}
+
+#[test]
+#[should_panic(expected = "outputs is shorter than inputs")]
+fn fnv1a_test_batch_short_outputs() {
+    fnv1a_hash_64_batch(&[b"a", b"b"], &mut [0; 1]);
+}
+
+#[test]
+fn fnv1a_test_from_iter() {
+    assert_eq!(fnv1a_hash_64_from_iter(b"hello".iter().copied(), false), fnv1a_hash_str_64("hello"));
+    assert_eq!(fnv1a_hash_64_from_iter(b"hello".iter().copied(), true), fnv1a_hash_str_case_64("hello"));
+
+    let chained = b"hello ".iter().chain(b"world").copied();
+    assert_eq!(fnv1a_hash_64_from_iter(chained, false), fnv1a_hash_64_chain(&[b"hello ", b"world"], false));
+    assert_eq!(fnv1a_hash_64_from_iter(core::iter::empty(), false), FNV_OFFSET_BASIS_64);
+}
+
+#[test]
+fn fnv1a_test_be_words() {
+    assert_eq!(fnv1a_hash_32_be_words(b"\x00\x00\x00\x01"), fnv1a_hash_32(&[0, 0, 0, 1], None, false));
+    assert_eq!(fnv1a_hash_32_be_words(b"abc"), fnv1a_hash_32(b"abc\0", None, false));
+    assert_eq!(fnv1a_hash_32_be_words(b"abcde"), fnv1a_hash_32(b"abcde\0\0\0", None, false));
+
+    let mut words = [0; 8];
+    words[..4].copy_from_slice(&0x0102_0304u32.to_be_bytes());
+    words[4..].copy_from_slice(&0x0506_0708u32.to_be_bytes());
+    assert_eq!(fnv1a_hash_32_be_words(&[1, 2, 3, 4, 5, 6, 7, 8]), fnv1a_hash_32(&words, None, false));
+    assert_eq!(fnv1a_hash_32_be_words(b""), FNV_OFFSET_BASIS_32);
+}
+
+#[test]
+fn fnv1a_test_masked_case() {
+    assert_eq!(fnv1a_hash_64_masked_case(b"hello", &[1, 0, 0, 0, 0]), fnv1a_hash_64(b"Hello", None, false));
+    assert_eq!(fnv1a_hash_64_masked_case(b"get/v1", &[1, 1, 1]), fnv1a_hash_64(b"GET/v1", None, false));
+    assert_eq!(fnv1a_hash_64_masked_case(b"hello", &[0; 5]), fnv1a_hash_64(b"hello", None, false));
+    assert_eq!(fnv1a_hash_64_masked_case(b"hello", &[]), fnv1a_hash_64(b"hello", None, false));
+    assert_eq!(fnv1a_hash_64_masked_case(b"hello", &[1; 5]), fnv1a_hash_64(b"hello", None, true));
+}
+
+#[test]
+fn fnv1a_test_cdc_scanner() {
+    const W: usize = 16;
+    let mut data = [0u8; 1 << 14];
+    for (i, chunk) in data.chunks_mut(8).enumerate() {
+        chunk.copy_from_slice(&fnv1a_hash_64_with_nonce(i as u64).to_le_bytes());
+    }
+
+    let boundaries = |mask: u32| {
+        let mut scanner = FnvCdcScanner::<W>::new(mask, 0);
+        let mut count = 0;
+        for (i, &byte) in data.iter().enumerate() {
+            let boundary = scanner.push(byte);
+            let reference = i + 1 >= W && fnv1a_hash_32(&data[i + 1 - W..=i], None, false) & mask == 0;
+            assert_eq!(boundary, reference);
+            count += boundary as usize;
+        }
+        count
+    };
+
+    let small = boundaries(0x0F);
+    let large = boundaries(0xFF);
+    assert!(small > 4 * large && large > 0);
+    assert_eq!(boundaries(0xFFFF_FFFF), 0);
+}
+
+#[test]
+fn fnv1a_test_static_dispatch() {
+    fn lookup(key: &str) -> u32 {
+        static_dispatch!(key => {
+            "alpha" => 1,
+            "beta" => 2,
+            "gamma" => 3,
+            _ => 0,
+        })
+    }
+
+    assert_eq!(lookup("alpha"), 1);
+    assert_eq!(lookup("beta"), 2);
+    assert_eq!(lookup("gamma"), 3);
+    assert_eq!(lookup("delta"), 0);
+    assert_eq!(static_dispatch!("any" => { _ => 7 }), 7);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn fnv1a_test_str_memoized() {
+    use std::boxed::Box;
+    use std::string::String;
+
+    const LITERAL: &str = "a string literal hashed every single frame";
+    assert_eq!(fnv1a_hash_str_64_memoized(LITERAL), fnv1a_hash_str_64(LITERAL));
+    assert_eq!(fnv1a_hash_str_64_memoized(LITERAL), fnv1a_hash_str_64(LITERAL));
+
+    let copy: &'static str = Box::leak(String::from(LITERAL).into_boxed_str());
+    assert_ne!(copy.as_ptr(), LITERAL.as_ptr());
+    assert_eq!(fnv1a_hash_str_64_memoized(copy), fnv1a_hash_str_64(LITERAL));
+    assert_eq!(fnv1a_hash_str_64_memoized(&LITERAL[..8]), fnv1a_hash_str_64(&LITERAL[..8]));
+}
+
+#[test]
+fn fnv1a_test_uuid() {
+    let uuid = fnv1a_uuid_from_bytes(1, b"device-42");
+    assert_eq!(uuid, fnv1a_uuid_from_bytes(1, b"device-42"));
+    assert_ne!(uuid, fnv1a_uuid_from_bytes(2, b"device-42"));
+    assert_ne!(uuid, fnv1a_uuid_from_bytes(1, b"device-43"));
+    assert_eq!(uuid[6] >> 4, 0xF);
+    assert_eq!(uuid[8] >> 6, 0b10);
+
+    let mut input = [0; 17];
+    input[7] = 1;
+    input[8..].copy_from_slice(b"device-42");
+    let hash = fnv1a_hash_128(&input, None, false).to_be_bytes();
+    assert_eq!(uuid[..6], hash[..6]);
+    assert_eq!(uuid[9..], hash[9..]);
+}
+
+#[test]
+fn fnv1a_test_stable() {
+    // These vectors are persisted by users and must never change.
+    assert_eq!(fnv1a_hash_64_stable(b""), 0xcbf29ce484222324);
+    assert_eq!(fnv1a_hash_64_stable(b"hello"), 0x1b6dad4264751614);
+    assert_eq!(fnv1a_hash_64_stable(b"foobar"), 0x005902726b028639);
+}
+
+#[test]
+fn fnv1a_test_reverse() {
+    assert_eq!(fnv1a_hash_64_reverse(b"ba", None), fnv1a_hash_64(b"ab", None, false));
+    assert_eq!(fnv1a_hash_str_64_reverse("racecar"), fnv1a_hash_str_64("racecar"));
+    assert_eq!(fnv1a_hash_str_64_reverse("moc.elpmaxe"), fnv1a_hash_str_64("example.com"));
+    assert_eq!(fnv1a_hash_64_reverse(b"file.rs", Some(3)), fnv1a_hash_64(b"sr.", None, false));
+    assert_eq!(fnv1a_hash_64_reverse(b"", None), FNV_OFFSET_BASIS_64);
+}
+
+#[test]
+fn fnv1a_test_bloom_filter() {
+    const N: u64 = 400;
+    const K: u32 = 7;
+    let mut filter = FnvBloomFilter::<64>::new(K);
+    assert_eq!(filter.bits_set(), 0);
+    for i in 0..N {
+        filter.insert(&i.to_le_bytes());
+    }
+    for i in 0..N {
+        assert!(filter.contains(&i.to_le_bytes()));
+    }
+    assert!(filter.bits_set() <= N as u32 * K);
+
+    let trials = 20_000;
+    let false_positives = (N..N + trials).filter(|i| filter.contains(&i.to_le_bytes())).count();
+    let rate = false_positives as f64 / trials as f64;
+
+    let bits = FnvBloomFilter::<64>::BITS as f64;
+    let expected = (1.0 - (-(K as f64) * N as f64 / bits).exp()).powi(K as i32);
+    assert!(rate > expected / 3.0 && rate < expected * 3.0, "rate {} expected {}", rate, expected);
+}
+
+#[test]
+fn fnv1a_test_case_sensitivity_names() {
+    for input in [&b""[..], b"Hello", b"MiXeD-case 42"].iter() {
+        assert_eq!(fnv1a_hash_64_case_sensitive(input, None), fnv1a_hash_64(input, None, false));
+        assert_eq!(fnv1a_hash_64_case_insensitive(input, None), fnv1a_hash_64(input, None, true));
+        assert_eq!(fnv1a_hash_32_case_sensitive(input, Some(3)), fnv1a_hash_32(input, Some(3), false));
+        assert_eq!(fnv1a_hash_32_case_insensitive(input, Some(3)), fnv1a_hash_32(input, Some(3), true));
+    }
+}
+
+#[test]
+fn fnv1a_test_32_and_64() {
+    for input in [&b""[..], b"a", b"two-level"].iter() {
+        assert_eq!(
+            fnv1a_hash_32_and_64(input, None, false),
+            (fnv1a_hash_32_case_sensitive(input, None), fnv1a_hash_64_case_sensitive(input, None))
+        );
+        assert_eq!(
+            fnv1a_hash_32_and_64(input, Some(2), true),
+            (fnv1a_hash_32_case_insensitive(input, Some(2)), fnv1a_hash_64_case_insensitive(input, Some(2)))
+        );
+    }
+}
+
+#[test]
+fn fnv1a_test_integer_slices_le() {
+    let expected = fnv1a_hash_64_case_sensitive(&[1, 0, 0, 0, 2, 0, 0, 0], None);
+    assert_eq!(fnv1a_hash_u32_slice_64(&[1, 2]), expected);
+    assert_eq!(fnv1a_hash_u16_slice_64(&[1, 0, 2, 0]), expected);
+    assert_eq!(fnv1a_hash_u64_slice_64(&[0x0000_0002_0000_0001]), expected);
+    assert_eq!(fnv1a_hash_u32_slice_64(&[]), FNV_OFFSET_BASIS_64);
+    assert_eq!(fnv1a_hash_u64_slice_64(&[]), FNV_OFFSET_BASIS_64);
+}
+
+#[test]
+fn fnv1a_test_32_debug() {
+    assert_eq!(fnv1a_hash_32_debug(b"Hello", None, true), (fnv1a_hash_32_case_insensitive(b"Hello", None), 4));
+    assert_eq!(fnv1a_hash_32_debug(b"HELLO", None, true).1, 0);
+    assert_eq!(fnv1a_hash_32_debug(b"hello", Some(2), true).1, 2);
+    assert_eq!(fnv1a_hash_32_debug(b"hello", None, false), (fnv1a_hash_32_case_sensitive(b"hello", None), 0));
+}
+
+#[test]
+fn fnv1a_test_start_step() {
+    let hash_32 = b"hello".iter().fold(fnv1a_start_32(), |state, &byte| fnv1a_step_32(state, byte));
+    let hash_64 = b"hello".iter().fold(fnv1a_start_64(), |state, &byte| fnv1a_step_64(state, byte));
+    assert_eq!(hash_32, fnv1a_hash_str_32("hello"));
+    assert_eq!(hash_64, fnv1a_hash_str_64("hello"));
+    assert_eq!(fnv1a_step_32(fnv1a_start_32(), b'A'), fnv1a_hash_str_32("A"));
+}
+
+#[cfg(feature = "unicode")]
+#[test]
+fn fnv1a_test_str_grapheme_count() {
+    assert_eq!(fnv1a_hash_str_64_grapheme_count("hello"), (fnv1a_hash_str_64("hello"), 5));
+    assert_eq!(fnv1a_hash_str_64_grapheme_count("e\u{301}"), (fnv1a_hash_str_64("e\u{301}"), 1));
+    assert_eq!(fnv1a_hash_str_64_grapheme_count(""), (FNV_OFFSET_BASIS_64, 0));
+}
+
+#[test]
+fn fnv1a_test_with_depth() {
+    assert_ne!(fnv1a_hash_64_with_depth(b"abc", 0), fnv1a_hash_64_with_depth(b"abc", 1));
+    assert_eq!(fnv1a_hash_64_with_depth(b"abc", 0), fnv1a_hash_64_case_sensitive(b"\x00abc", None));
+    assert_eq!(fnv1a_hash_64_with_depth(b"", 7), fnv1a_hash_64_case_sensitive(b"\x07", None));
+}
+
+#[test]
+fn fnv1a_test_str_pair() {
+    assert_ne!(fnv1a_hash_str_pair_64("a", "bc"), fnv1a_hash_str_pair_64("ab", "c"));
+    assert_ne!(fnv1a_hash_str_pair_64("x", "y"), fnv1a_hash_str_pair_64("y", "x"));
+    assert_eq!(fnv1a_hash_str_pair_64("plugin", "name"), fnv1a_hash_64_pair(b"plugin", b"name"));
+    assert_eq!(fnv1a_hash_str_pair_case_64("Plugin", "name"), fnv1a_hash_str_pair_64("PLUGIN", "NAME"));
+    assert_ne!(fnv1a_hash_str_pair_case_64("a", "bc"), fnv1a_hash_str_pair_case_64("ab", "c"));
+}
+
+#[test]
+fn fnv1a_test_display() {
+    assert_eq!(fnv1a_hash_display_64(42u32), fnv1a_hash_str_64("42"));
+    assert_eq!(fnv1a_hash_display_64("hello"), fnv1a_hash_str_64("hello"));
+    assert_eq!(fnv1a_hash_display_64(core::net::Ipv4Addr::new(10, 0, 0, 1)), fnv1a_hash_str_64("10.0.0.1"));
+    assert_eq!(FnvFmtHasher64::default().finalize(), FNV_OFFSET_BASIS_64);
+}
+
+#[test]
+fn fnv1a_test_probe_sequence() {
+    const TABLE_SIZE: usize = 101;
+    let h1 = fnv1a_hash_64_case_sensitive(b"probe", None);
+    let h2 = fnv1a_hash_32_case_sensitive(b"probe", None) | 1;
+
+    let probes: [usize; TABLE_SIZE] = {
+        let mut probes = [0; TABLE_SIZE];
+        let mut sequence = fnv1a_probe_sequence(b"probe", TABLE_SIZE);
+        for probe in probes.iter_mut() {
+            *probe = sequence.next().unwrap();
+        }
+        probes
+    };
+    assert_eq!(probes[0], (h1 % TABLE_SIZE as u64) as usize);
+    assert_eq!(probes[1], ((h1 % TABLE_SIZE as u64 + h2 as u64) % TABLE_SIZE as u64) as usize);
+
+    let mut seen = [false; TABLE_SIZE];
+    for &probe in probes.iter() {
+        assert!(!seen[probe]);
+        seen[probe] = true;
+    }
+    assert_eq!(fnv1a_probe_sequence(b"probe", TABLE_SIZE).nth(TABLE_SIZE), Some(probes[0]));
+    assert!(fnv1a_probe_sequence(b"x", 1).take(3).all(|probe| probe == 0));
+}
+
+#[test]
+fn fnv1a_test_case_range() {
+    assert_eq!(fnv1a_hash_64_case_range(b"Hello World", 0, 5), fnv1a_hash_64_case_sensitive(b"HELLO World", None));
+    assert_eq!(fnv1a_hash_64_case_range(b"http://Path", 0, 4), fnv1a_hash_64_case_sensitive(b"HTTP://Path", None));
+    assert_eq!(fnv1a_hash_64_case_range(b"Hello", 3, 3), fnv1a_hash_64_case_sensitive(b"Hello", None));
+    assert_eq!(fnv1a_hash_64_case_range(b"Hello", 0, 100), fnv1a_hash_64_case_insensitive(b"Hello", None));
+}
+
+#[test]
+fn fnv1a_test_mod_prime() {
+    for input in [&b""[..], b"a", b"karp-rabin"].iter() {
+        let hash = fnv1a_hash_32_case_sensitive(input, None);
+        assert!(fnv1a_hash_32_mod_prime(input, (1 << 31) - 1) < (1 << 31) - 1);
+        assert!(fnv1a_hash_32_mod_prime(input, (1 << 13) - 1) < (1 << 13) - 1);
+        assert_eq!(fnv1a_hash_32_mod_prime(input, 1), 0);
+        assert_eq!(fnv1a_hash_32_mod_prime(input, u32::MAX), hash);
+    }
+}
+
+#[test]
+#[should_panic(expected = "prime must be non-zero")]
+fn fnv1a_test_mod_prime_zero() {
+    fnv1a_hash_32_mod_prime(b"a", 0);
+}
+
+#[test]
+fn fnv1a_test_debug_bits() {
+    assert_eq!(fnv1a_hash_64_debug_bits(b""), (FNV_OFFSET_BASIS_64, [false; 64]));
+
+    let (hash, bits) = fnv1a_hash_64_debug_bits(b"a");
+    assert_eq!(hash, fnv1a_hash_64_case_sensitive(b"a", None));
+    assert!(bits.iter().filter(|&&bit| bit).count() > 8);
+    assert_eq!(bits.iter().filter(|&&bit| bit).count() as u32, (hash ^ FNV_OFFSET_BASIS_64).count_ones());
+}
+
+#[test]
+fn fnv1a_test_interleaved() {
+    assert_eq!(fnv1a_hash_64_interleaved(b"ac", b"bd"), fnv1a_hash_64_case_sensitive(b"abcd", None));
+    assert_ne!(fnv1a_hash_64_interleaved(b"ac", b"bd"), fnv1a_hash_64_interleaved(b"bd", b"ac"));
+    assert_eq!(fnv1a_hash_64_interleaved(b"aceg", b"bd"), fnv1a_hash_64_case_sensitive(b"abcdeg", None));
+    assert_eq!(fnv1a_hash_64_interleaved(b"a", b"bdf"), fnv1a_hash_64_case_sensitive(b"abdf", None));
+    assert_eq!(fnv1a_hash_64_interleaved(b"", b""), FNV_OFFSET_BASIS_64);
+}
+
+#[test]
+fn fnv1a_test_scatter() {
+    assert_eq!(fnv1a_hash_64_scatter(&[(0, b'h'), (1, b'i')], 2), fnv1a_hash_str_64("hi"));
+    assert_eq!(fnv1a_hash_64_scatter(&[(3, b'b'), (1, b'a')], 5), fnv1a_hash_64_case_sensitive(b"\0a\0b\0", None));
+    assert_eq!(fnv1a_hash_64_scatter(&[], 3), fnv1a_hash_64_case_sensitive(&[0; 3], None));
+    assert_eq!(fnv1a_hash_64_scatter(&[], 0), FNV_OFFSET_BASIS_64);
+}
+
+#[test]
+#[should_panic(expected = "duplicate scatter index")]
+fn fnv1a_test_scatter_duplicate() {
+    fnv1a_hash_64_scatter(&[(1, b'a'), (1, b'b')], 2);
+}
+
+#[test]
+#[should_panic(expected = "scatter index out of range")]
+fn fnv1a_test_scatter_out_of_range() {
+    fnv1a_hash_64_scatter(&[(2, b'a')], 2);
+}
+
+#[test]
+fn fnv1a_test_str_array() {
+    const HASHES: [u32; 3] = fnv1a_hash_str_array_32(&["a", "b", "c"]);
+    assert_eq!(HASHES, [fnv1a_hash_str_32("a"), fnv1a_hash_str_32("b"), fnv1a_hash_str_32("c")]);
+    assert_eq!(fnv1a_hash_str_array_64(&["a", "b"]), [fnv1a_hash_str_64("a"), fnv1a_hash_str_64("b")]);
+    assert_eq!(fnv1a_hash_str_array_32(&[]), [0u32; 0]);
+}
+
+#[test]
+fn fnv1a_test_undo_step() {
+    const _: () = assert!(FNV_PRIME_32.wrapping_mul(FNV_PRIME_32_INVERSE) == 1);
+    const _: () = assert!(FNV_PRIME_64.wrapping_mul(FNV_PRIME_64_INVERSE) == 1);
+
+    for nonce in 0..4096u64 {
+        let state = fnv1a_hash_64_with_nonce(nonce);
+        let byte = (nonce * 31) as u8;
+        assert_eq!(fnv1a_undo_step_64(fnv1a_step_64(state, byte), byte), state);
+        assert_eq!(fnv1a_undo_step_32(fnv1a_step_32(state as u32, byte), byte), state as u32);
+    }
+
+    let hash = fnv1a_hash_str_64("ab");
+    assert_eq!(fnv1a_undo_step_64(fnv1a_undo_step_64(hash, b'b'), b'a'), FNV_OFFSET_BASIS_64);
+}
+
+#[test]
+fn fnv1a_test_count_ones() {
+    assert_eq!(fnv1a_hash_64_count_ones(b""), (FNV_OFFSET_BASIS_64, FNV_OFFSET_BASIS_64.count_ones()));
+
+    let mut total = 0;
+    for i in 0..1024u32 {
+        let (hash, ones) = fnv1a_hash_64_count_ones(&i.to_le_bytes());
+        assert_eq!(hash, fnv1a_hash_64_case_sensitive(&i.to_le_bytes(), None));
+        assert!(ones <= 64);
+        total += ones;
+    }
+    let average = total / 1024;
+    assert!((28..=36).contains(&average));
+}
+
+#[test]
+fn fnv1a_test_str_case_before() {
+    assert_eq!(
+        fnv1a_hash_str_case_before_64("Content-Type: text/html", b':'),
+        fnv1a_hash_64_chain(&[b"CONTENT-TYPE", b": text/html"], false)
+    );
+    assert_eq!(fnv1a_hash_str_case_before_64("Content-Type", b':'), fnv1a_hash_str_64("CONTENT-TYPE"));
+    assert_eq!(fnv1a_hash_str_case_before_64(":Value", b':'), fnv1a_hash_str_64(":Value"));
+}
+
+#[test]
+fn fnv1a_test_take_n() {
+    let hash = fnv1a_hash_64_case_sensitive(b"take", None);
+    assert!(fnv1a_hash_64_take_n::<32>(b"take") <= u32::MAX as u64);
+    assert_eq!(fnv1a_hash_64_take_n::<32>(b"take"), fnv1a_hash_64_bits(b"take", 32));
+    assert!(fnv1a_hash_64_take_n::<1>(b"take") <= 1);
+    assert_eq!(fnv1a_hash_64_take_n::<64>(b"take"), hash);
+    assert_eq!(fnv1a_hash_64_take_n::<0>(b"take"), 0);
+}
+
+#[test]
+fn fnv1a_test_sparse() {
+    assert_eq!(fnv1a_hash_64_sparse(b"abcde", &[0, 2, 4]), fnv1a_hash_64_case_sensitive(b"ace", None));
+    assert_eq!(fnv1a_hash_64_sparse(b"abcde", &[1, 1]), fnv1a_hash_64_case_sensitive(b"bb", None));
+    assert_eq!(fnv1a_hash_64_sparse(b"abcde", &[0, 9]), fnv1a_hash_64_case_sensitive(b"a\0", None));
+    assert_eq!(fnv1a_hash_64_sparse(b"abcde", &[]), FNV_OFFSET_BASIS_64);
+}
+
+#[test]
+fn fnv1a_test_aligned() {
+    assert_eq!(fnv1a_hash_32_aligned(&[0x04030201u32]), fnv1a_hash_32_case_sensitive(&[1, 2, 3, 4], None));
+    assert_eq!(fnv1a_hash_32_aligned(&[]), FNV_OFFSET_BASIS_32);
+}
+
+#[test]
+fn fnv1a_test_cascade() {
+    let hash = fnv1a_hash_64_case_sensitive(b"cascade", None);
+    assert_eq!(fnv1a_hash_64_cascade(b"cascade", 0), FNV_OFFSET_BASIS_64);
+    assert_eq!(fnv1a_hash_64_cascade(b"cascade", 1), hash);
+    assert_eq!(fnv1a_hash_64_cascade(b"cascade", 2), fnv1a_hash_u64_64(hash));
+    assert_ne!(fnv1a_hash_64_cascade(b"cascade", 2), hash);
+}
+
+#[test]
+fn fnv1a_test_to_hex() {
+    assert_eq!(&fnv1a_hash_str_to_hex32(""), b"811c9dc5");
+    assert_eq!(&fnv1a_hash_str_to_hex64(""), b"cbf29ce484222325");
+    assert_eq!(&fnv1a_hash_str_to_hex32("hello"), b"4f9f2cab");
+
+    let hex = fnv1a_hash_str_to_hex64("hello");
+    assert!(hex.is_ascii());
+    let hex = core::str::from_utf8(&hex).unwrap();
+    assert_eq!(u64::from_str_radix(hex, 16), Ok(fnv1a_hash_str_64("hello")));
+}
+
+#[test]
+fn fnv1a_test_consistent_bucket() {
+    let mut counts = [0u32; 4];
+    let mut moved = 0;
+    for i in 0..10_000u32 {
+        let key = i.to_le_bytes();
+        let before = fnv1a_hash_64_consistent_bucket(&key, 4);
+        let after = fnv1a_hash_64_consistent_bucket(&key, 5);
+        counts[before as usize] += 1;
+        if before != after {
+            assert_eq!(after, 4);
+            moved += 1;
+        }
+    }
+    assert!(counts.iter().all(|&count| (2250..=2750).contains(&count)));
+    assert!((1750..=2250).contains(&moved));
+    assert_eq!(fnv1a_hash_64_consistent_bucket(b"any", 1), 0);
+}
+
+#[test]
+fn fnv1a_test_mixed() {
+    const MIXED: u64 = fnv1a_hash_64_mixed(b"mixed");
+    assert_ne!(MIXED, fnv1a_hash_64_case_sensitive(b"mixed", None));
+    assert_ne!(fnv1a_hash_64_mixed(b""), FNV_OFFSET_BASIS_64);
+
+    let mut flipped = 0;
+    let mut trials = 0;
+    for i in 0..1000u64 {
+        let input = fnv1a_hash_64_with_nonce(i).to_le_bytes();
+        let hash = fnv1a_hash_64_mixed(&input);
+        for bit in 0..64 {
+            let mut changed = input;
+            changed[bit / 8] ^= 1 << (bit % 8);
+            flipped += (fnv1a_hash_64_mixed(&changed) ^ hash).count_ones();
+            trials += 1;
+        }
+    }
+    assert!(flipped / trials >= 30);
+}
+
+#[test]
+fn fnv1a_test_str_32_debug() {
+    assert_eq!(fnv1a_hash_str_32_debug("hello", Some(3)), (fnv1a_hash_str_32_limit("hello", 3), "hel"));
+    assert_eq!(fnv1a_hash_str_32_debug("hello", None), (fnv1a_hash_str_32("hello"), "hello"));
+    assert_eq!(fnv1a_hash_str_32_debug("hello", Some(0)).1, "hello");
+    assert_eq!(fnv1a_hash_str_32_debug("caf\u{e9}", Some(4)), (fnv1a_hash_str_32("caf"), "caf"));
+}
+
+#[test]
+fn fnv1a_test_bucket_assign() {
+    let single = [(1 << 40, 7)];
+    let halves = [(u64::MAX / 2, 0), (u64::MAX, 1)];
+
+    let mut counts = [0u32; 2];
+    for i in 0..10_000u32 {
+        let key = i.to_le_bytes();
+        assert_eq!(fnv1a_bucket_assign(&key, &single), 7);
+        counts[fnv1a_bucket_assign(&key, &halves) as usize] += 1;
+    }
+    assert!(counts.iter().all(|&count| (4500..=5500).contains(&count)));
+
+    let hash = fnv1a_hash_64_case_sensitive(b"wrap", None);
+    assert_eq!(fnv1a_bucket_assign(b"wrap", &[(hash - 1, 3), (hash, 4)]), 4);
+    assert_eq!(fnv1a_bucket_assign(b"wrap", &[(0, 5), (hash - 1, 6)]), 5);
+}
+
+#[test]
+#[should_panic(expected = "virtual_nodes must be sorted by ring position")]
+fn fnv1a_test_bucket_assign_unsorted() {
+    fnv1a_bucket_assign(b"key", &[(2, 0), (1, 1)]);
+}
+
+#[test]
+fn fnv1a_test_multiset() {
+    let abc = fnv1a_hash_multiset_32(b"abc");
+    for permutation in [b"acb", b"bac", b"bca", b"cab", b"cba"].iter() {
+        assert_eq!(fnv1a_hash_multiset_32(*permutation), abc);
+    }
+    assert_eq!(fnv1a_hash_multiset_32(b"aab"), fnv1a_hash_multiset_32(b"aba"));
+    assert_ne!(fnv1a_hash_multiset_32(b"aab"), fnv1a_hash_multiset_32(b"ab"));
+    assert_eq!(fnv1a_hash_multiset_32(b""), FNV_OFFSET_BASIS_32);
+}
+
+#[test]
+fn fnv1a_test_with_epoch() {
+    assert_ne!(fnv1a_hash_64_with_epoch(b"cached", 0), fnv1a_hash_64_with_epoch(b"cached", 1));
+    assert_ne!(fnv1a_hash_64_with_epoch(b"cached", 0), fnv1a_hash_64_case_sensitive(b"cached", None));
+    assert_eq!(
+        fnv1a_hash_64_with_epoch(b"cached", 9),
+        fnv1a_hash_64_chain(&[&9u64.to_le_bytes(), b"cached"], false)
+    );
+}
+
+#[test]
+fn fnv1a_test_deterministic_shuffle() {
+    const SHUFFLED: [usize; 32] = fnv1a_deterministic_shuffle(b"seed");
+
+    let mut seen = [false; 32];
+    for &index in SHUFFLED.iter() {
+        assert!(!seen[index]);
+        seen[index] = true;
+    }
+    assert_eq!(fnv1a_deterministic_shuffle::<32>(b"seed"), SHUFFLED);
+    assert_ne!(fnv1a_deterministic_shuffle::<32>(b"other seed"), SHUFFLED);
+    assert_eq!(fnv1a_deterministic_shuffle::<1>(b"seed"), [0]);
+    assert_eq!(fnv1a_deterministic_shuffle::<0>(b"seed"), []);
+}
+
+#[test]
+fn fnv1a_test_path() {
+    assert_ne!(fnv1a_hash_path_64(&["a", "b"]), fnv1a_hash_path_64(&["ab"]));
+    assert_ne!(fnv1a_hash_path_64(&["a", "b", "c"]), fnv1a_hash_path_64(&["a", "bc"]));
+    assert_ne!(fnv1a_hash_path_64(&["a", "b", "c"]), fnv1a_hash_path_64(&["ab", "c"]));
+    assert_eq!(fnv1a_hash_path_64(&["a", "b"]), fnv1a_hash_str_64("/a/b"));
+    assert_eq!(fnv1a_hash_path_64(&[]), FNV_OFFSET_BASIS_64);
+}
+
+#[test]
+fn fnv1a_test_str_case_boundary() {
+    assert_eq!(fnv1a_hash_str_32_case_boundary("helloWorld", 5), fnv1a_hash_str_32("HELLOWorld"));
+    assert_eq!(fnv1a_hash_str_32_case_boundary("api/v1/MyResource", 7), fnv1a_hash_str_32("API/V1/MyResource"));
+    assert_eq!(fnv1a_hash_str_32_case_boundary("HELLOworld", 0), fnv1a_hash_str_32("HELLOworld"));
+    assert_eq!(fnv1a_hash_str_32_case_boundary("hello", 99), fnv1a_hash_str_case_32("hello"));
+}
+
+#[test]
+fn fnv1a_test_dispatch_table() {
+    const KEYS: [&str; 8] = ["get", "put", "post", "head", "patch", "delete", "trace", "options"];
+    const TABLE: [(u64, usize); 8] = fnv1a_build_dispatch_table(&KEYS);
+
+    assert!(TABLE.windows(2).all(|pair| pair[0].0 <= pair[1].0));
+    for (index, key) in KEYS.iter().enumerate() {
+        assert_eq!(fnv1a_dispatch_lookup(&TABLE, &KEYS, key), Some(index));
+    }
+    assert_eq!(fnv1a_dispatch_lookup(&TABLE, &KEYS, "connect"), None);
+
+    // An entry whose hash matches the key but which refers to different content.
+    let colliding = [(fnv1a_hash_str_64("intruder"), 0)];
+    assert_eq!(fnv1a_dispatch_lookup(&colliding, &KEYS, "intruder"), None);
+    assert_eq!(fnv1a_dispatch_lookup(&[], &[], "get"), None);
+}
+
+#[test]
+fn fnv1a_test_bit_frequency_check() {
+    const COUNTS: [u32; 64] = fnv1a_hash_64_bit_frequency_check();
+    assert!(COUNTS.iter().all(|&count| count <= 256));
+    // The low byte is a bijection of the input byte (odd prime), so it is perfectly balanced.
+    assert!(COUNTS[..8].iter().all(|&count| count == 128));
+    assert_eq!(COUNTS[18], 0);
+    assert_eq!(COUNTS[25], 256);
+
+    let total: u32 = COUNTS.iter().sum();
+    assert_eq!(total, 7305);
+}
+
+#[test]
+fn fnv1a_test_zigzag() {
+    assert_ne!(fnv1a_hash_i32_zigzag_64(-1), fnv1a_hash_i32_zigzag_64(1));
+    assert_eq!(fnv1a_hash_i32_zigzag_64(0), fnv1a_hash_u32_64(0));
+    assert_eq!(fnv1a_hash_i32_zigzag_64(-1), fnv1a_hash_u32_64(1));
+    assert_eq!(fnv1a_hash_i32_zigzag_64(1), fnv1a_hash_u32_64(2));
+    assert_eq!(fnv1a_hash_i32_zigzag_64(i32::MIN), fnv1a_hash_u32_64(u32::MAX));
+
+    assert_eq!(fnv1a_hash_i64_zigzag_64(-2), fnv1a_hash_u64_64(3));
+    assert_eq!(fnv1a_hash_i16_zigzag_64(-1), fnv1a_hash_u16_64(1));
+    assert_eq!(fnv1a_hash_i8_zigzag_64(i8::MAX), fnv1a_hash_u8_64(254));
+}
+
+#[test]
+fn fnv1a_test_lines() {
+    let mut lines = fnv1a_hash_lines_64("hello");
+    assert_eq!(lines.next(), Some((0, fnv1a_hash_str_64("hello"))));
+    assert_eq!(lines.next(), None);
+
+    let mut lines = fnv1a_hash_lines_64("a\nb");
+    assert_eq!(lines.next(), Some((0, fnv1a_hash_str_64("a"))));
+    assert_eq!(lines.next(), Some((1, fnv1a_hash_str_64("b"))));
+    assert_eq!(lines.next(), None);
+
+    let mut lines = fnv1a_hash_lines_64("");
+    assert_eq!(lines.next(), Some((0, FNV_OFFSET_BASIS_64)));
+    assert_eq!(lines.next(), None);
+
+    let mut lines = fnv1a_hash_lines_64("a\r\nb");
+    assert_eq!(lines.next(), Some((0, fnv1a_hash_str_64("a\r"))));
+    assert_eq!(lines.next(), Some((1, fnv1a_hash_str_64("b"))));
+}
+
+#[test]
+fn fnv1a_test_struct_fields() {
+    struct Record {
+        id: u8,
+        name: &'static str,
+        tag: [u8; 2],
+    }
+
+    let record = Record { id: 7, name: "alpha", tag: *b"ok" };
+    let fields = fnv1a_struct_fields!(record, id, name, tag);
+    assert_eq!(fields.len(), 3);
+    assert_ne!(fields[0].0, fields[1].0);
+    assert_ne!(fields[1].0, fields[2].0);
+    assert_ne!(fields[0].0, fields[2].0);
+    assert_eq!(fields[0], (fnv1a_hash_str_64("id"), fnv1a_hash_u8_64(7)));
+
+    let changed = Record { name: "beta", ..record };
+    let changed_fields = fnv1a_struct_fields!(changed, id, name, tag);
+    assert_eq!(changed_fields[0], fields[0]);
+    assert_ne!(changed_fields[1], fields[1]);
+    assert_eq!(changed_fields[1].0, fields[1].0);
+    assert_eq!(changed_fields[2], fields[2]);
+}
+
+#[test]
+fn fnv1a_test_url_components() {
+    const URL: UrlHash = fnv1a_hash_url_32("http://example.com/path?q=1");
+    assert_eq!(URL.scheme, fnv1a_hash_str_32("http"));
+    assert_eq!(URL.host, fnv1a_hash_str_32("example.com"));
+    assert_eq!(URL.path, fnv1a_hash_str_32("/path"));
+    assert_eq!(URL.query, fnv1a_hash_str_32("q=1"));
+    assert_eq!(URL.combined, fnv1a_hash_str_32("http://example.com/path?q=1"));
+
+    let components = [URL.scheme, URL.host, URL.path, URL.query, URL.combined];
+    assert!(components.iter().all(|&hash| hash != 0));
+    for (i, a) in components.iter().enumerate() {
+        assert!(components[i + 1..].iter().all(|b| a != b));
+    }
+
+    let other = fnv1a_hash_url_32("http://example.com/path?q=2");
+    assert_eq!((other.scheme, other.host, other.path), (URL.scheme, URL.host, URL.path));
+    assert_ne!(other.query, URL.query);
+    assert_ne!(other.combined, URL.combined);
+
+    let bare = fnv1a_hash_url_32("example.com?q=1");
+    assert_eq!(bare.scheme, FNV_OFFSET_BASIS_32);
+    assert_eq!(bare.host, URL.host);
+    assert_eq!(bare.path, FNV_OFFSET_BASIS_32);
+    assert_eq!(bare.query, URL.query);
+}
+
+#[test]
+fn fnv1a_test_bitvector() {
+    assert_eq!(fnv1a_hash_bitvector_64(&[3], 8), fnv1a_hash_bitvector_64(&[3 | 1 << 8 | 1 << 63], 8));
+    assert_eq!(fnv1a_hash_bitvector_64(&[3], 8), fnv1a_hash_u8_64(3));
+    assert_eq!(fnv1a_hash_bitvector_64(&[0xff], 4), fnv1a_hash_u8_64(0x0f));
+    assert_eq!(fnv1a_hash_bitvector_64(&[3], 0), FNV_OFFSET_BASIS_64);
+    assert_eq!(fnv1a_hash_bitvector_64(&[], 100), FNV_OFFSET_BASIS_64);
+
+    assert_eq!(fnv1a_hash_bitvector_64(&[u64::MAX, 5], 128), fnv1a_hash_64_case_sensitive(&[0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 5, 0, 0, 0, 0, 0, 0, 0], None));
+    assert_eq!(fnv1a_hash_bitvector_64(&[1, 5], 67), fnv1a_hash_bitvector_64(&[1, 13], 67));
+    assert_ne!(fnv1a_hash_bitvector_64(&[1, 5], 67), fnv1a_hash_bitvector_64(&[1, 6], 67));
+}
+
+#[test]
+fn fnv1a_test_histogram() {
+    const ZERO: u32 = fnv1a_hash_histogram_32(&[0; 256]);
+    assert_eq!(ZERO, 0x1f116dc5);
+    assert_eq!(ZERO, fnv1a_hash_32_case_sensitive(&[0; 1024], None));
+
+    let mut histogram = [0; 256];
+    histogram[b'a' as usize] = 2;
+    histogram[b'b' as usize] = 1;
+    assert_eq!(fnv1a_hash_histogram_32(&histogram), fnv1a_hash_multiset_32(b"aba"));
+
+    let mut other = histogram;
+    other[b'b' as usize] = 2;
+    assert_ne!(fnv1a_hash_histogram_32(&other), fnv1a_hash_histogram_32(&histogram));
+}
+
+#[test]
+fn fnv1a_test_hamming_distance() {
+    assert_eq!(fnv1a_hash_delta_64(b"hello", b"hello"), 0);
+    assert_eq!(fnv1a_hash_hamming_distance_64(b"hello", b"hello"), 0);
+    assert_eq!(fnv1a_hash_delta_64(b"a", b"b"), fnv1a_hash_64_case_sensitive(b"a", None) ^ fnv1a_hash_64_case_sensitive(b"b", None));
+
+    let corpus: [&[u8]; 8] = [b"alpha", b"bravo", b"charlie", b"delta", b"echo", b"foxtrot", b"golf", b"hotel"];
+    let mut total = 0;
+    let mut pairs = 0;
+    for (i, a) in corpus.iter().enumerate() {
+        for b in &corpus[i + 1..] {
+            total += fnv1a_hash_hamming_distance_64(a, b);
+            pairs += 1;
+        }
+    }
+    let average = total / pairs;
+    assert!((28..=36).contains(&average));
+}
+
+#[test]
+fn fnv1a_test_window() {
+    assert_ne!(fnv1a_hash_window_64(b"abc", b"def"), fnv1a_hash_window_64(b"abcdef", b""));
+    assert_eq!(fnv1a_hash_window_64(b"abc", b"def"), fnv1a_hash_64_case_sensitive(b"abc\0def", None));
+    assert_ne!(fnv1a_hash_window_64(b"abc", b"def"), fnv1a_hash_64_case_sensitive(b"abc\x01def", None));
+    assert_eq!(fnv1a_hash_window_64(b"", b""), fnv1a_hash_u8_64(0));
+}
+
+#[test]
+fn fnv1a_test_bool_slice() {
+    assert_eq!(fnv1a_hash_bool_slice_64(&[true, false]), fnv1a_hash_64_case_sensitive(&[2, 0, 0, 0, 0, 0, 0, 0, 0b01], None));
+    assert_eq!(
+        fnv1a_hash_bool_slice_64(&[false, true, true, false, false, false, false, false, true]),
+        fnv1a_hash_64_case_sensitive(&[9, 0, 0, 0, 0, 0, 0, 0, 0b110, 0b1], None)
+    );
+    assert_ne!(fnv1a_hash_bool_slice_64(&[true, false]), fnv1a_hash_bool_slice_64(&[true, false, false]));
+    assert_ne!(fnv1a_hash_bool_slice_64(&[false; 8]), fnv1a_hash_bool_slice_64(&[false; 16]));
+    assert_eq!(fnv1a_hash_bool_slice_64(&[]), fnv1a_hash_u64_64(0));
+}
+
+#[test]
+fn fnv1a_test_camel_to_snake() {
+    assert_eq!(fnv1a_hash_str_32_camel_to_snake("UserName"), fnv1a_hash_str_32("user_name"));
+    assert_eq!(fnv1a_hash_str_32_camel_to_snake("userName"), fnv1a_hash_str_32("user_name"));
+    assert_eq!(fnv1a_hash_str_32_camel_to_snake("already_snake"), fnv1a_hash_str_32("already_snake"));
+    assert_eq!(fnv1a_hash_str_32_camel_to_snake("User_Name"), fnv1a_hash_str_32("user_name"));
+    assert_eq!(fnv1a_hash_str_32_camel_to_snake("HTML"), fnv1a_hash_str_32("h_t_m_l"));
+    assert_eq!(fnv1a_hash_str_32_camel_to_snake(""), FNV_OFFSET_BASIS_32);
+}
+
+#[test]
+fn fnv1a_test_derive_seeds() {
+    const SEEDS: [u64; 16] = fnv1a_derive_seeds_64(42);
+    for (i, seed) in SEEDS.iter().enumerate() {
+        assert!(SEEDS[i + 1..].iter().all(|other| other != seed));
+    }
+    assert_eq!(SEEDS[0], fnv1a_hash_u64_64(42));
+    assert_eq!(SEEDS[1], fnv1a_hash_u64_64(SEEDS[0]));
+
+    assert_eq!(fnv1a_derive_seeds_64::<0>(42), []);
+    assert_eq!(fnv1a_derive_seeds_64::<1>(42), [SEEDS[0]]);
+    assert_ne!(fnv1a_derive_seeds_64::<1>(43), [SEEDS[0]]);
+}
+
+#[test]
+fn fnv1a_test_weighted() {
+    assert_eq!(fnv1a_hash_64_weighted(b"hello", &[1]), fnv1a_hash_64_case_sensitive(b"hello", None));
+    assert_eq!(fnv1a_hash_64_weighted(b"hello", &[]), fnv1a_hash_64_case_sensitive(b"hello", None));
+    assert_eq!(fnv1a_hash_64_weighted(b"hello", &[0]), fnv1a_hash_64_case_sensitive(&[0; 5], None));
+    assert_eq!(fnv1a_hash_64_weighted(b"ab", &[1, 2]), 0x089b9e07b5447e58);
+    assert_eq!(fnv1a_hash_64_weighted(b"ab", &[1, 2]), fnv1a_hash_64_case_sensitive(&[b'a', b'b' * 2], None));
+    assert_eq!(fnv1a_hash_64_weighted(&[0x80], &[2]), fnv1a_hash_u8_64(0));
+}
+
+#[test]
+fn fnv1a_test_str_safe() {
+    assert_eq!(fnv1a_hash_str_64_safe("h\u{e9}llo"), fnv1a_hash_str_64("H\u{e9}LLO"));
+    assert_eq!(fnv1a_hash_str_64_safe("h\u{e9}llo"), fnv1a_hash_str_64_safe("H\u{e9}lLo"));
+    assert_eq!(fnv1a_hash_str_64_safe("\u{e9}\u{1f600}\u{c9}"), fnv1a_hash_str_64("\u{e9}\u{1f600}\u{c9}"));
+    assert_ne!(fnv1a_hash_str_64_safe("\u{e9}"), fnv1a_hash_str_64_safe("\u{c9}"));
+}
+
+#[test]
+fn fnv1a_test_multi_hash() {
+    let mut hashes = [0; 8];
+    for (slot, hash) in hashes.iter_mut().zip(fnv1a_multi_hash_32(b"hello", 8)) {
+        *slot = hash;
+    }
+    for (i, hash) in hashes.iter().enumerate() {
+        assert!(hashes[i + 1..].iter().all(|other| other != hash));
+    }
+    assert_eq!(fnv1a_multi_hash_32(b"hello", 8).count(), 8);
+
+    let mut single = fnv1a_multi_hash_32(b"hello", 1);
+    assert_eq!(single.next(), Some(fnv1a_hash_32_case_sensitive(b"hello", None)));
+    assert_eq!(single.next(), None);
+    assert_eq!(fnv1a_multi_hash_32(b"hello", 0).next(), None);
+}
+
+#[test]
+fn fnv1a_test_nibble_swapped() {
+    assert_eq!(fnv1a_hash_nibble_swapped_64(b"\xAB"), fnv1a_hash_64_case_sensitive(b"\xBA", None));
+    assert_eq!(fnv1a_hash_nibble_swapped_64(b"\x12\x34"), fnv1a_hash_64_case_sensitive(b"\x21\x43", None));
+    assert_eq!(fnv1a_hash_nibble_swapped_64(&[0, 0]), fnv1a_hash_64_case_sensitive(&[0, 0], None));
+    assert_eq!(fnv1a_hash_nibble_swapped_64(b""), FNV_OFFSET_BASIS_64);
+
+    let bytes = *b"hello";
+    let mut swapped = bytes;
+    for byte in swapped.iter_mut() {
+        *byte = byte.rotate_left(4);
+    }
+    assert_eq!(fnv1a_hash_nibble_swapped_64(&swapped), fnv1a_hash_64_case_sensitive(&bytes, None));
+}
+
+#[test]
+fn fnv1a_test_file_block() {
+    assert_ne!(fnv1a_hash_file_block_32(1, 0), fnv1a_hash_file_block_32(0, 1));
+    assert_eq!(fnv1a_hash_file_block_32(1, 2), fnv1a_hash_32_case_sensitive(&[1, 0, 0, 0, 2, 0, 0, 0], None));
+    assert_eq!(fnv1a_hash_file_block_32(0x0403_0201, 0x0807_0605), fnv1a_hash_32_case_sensitive(&[1, 2, 3, 4, 5, 6, 7, 8], None));
+
+    assert_ne!(fnv1a_hash_file_block_64(1, 0), fnv1a_hash_file_block_64(0, 1));
+    assert_eq!(fnv1a_hash_file_block_64(0x0403_0201, 0x0807_0605), fnv1a_hash_64_case_sensitive(&[1, 2, 3, 4, 5, 6, 7, 8], None));
+}
+
+#[test]
+fn fnv1a_test_continue() {
+    assert_eq!(fnv1a_hash_64_continue(FNV_OFFSET_BASIS_64, b"hello"), fnv1a_hash_str_64("hello"));
+    assert_eq!(fnv1a_hash_64_continue(FNV_OFFSET_BASIS_64, b""), FNV_OFFSET_BASIS_64);
+
+    let hash = fnv1a_hash_64_continue(FNV_OFFSET_BASIS_64, b"hello ");
+    assert_eq!(fnv1a_hash_64_continue(hash, b"world"), fnv1a_hash_str_64("hello world"));
+}
+
+#[test]
+fn fnv1a_test_bit_reversed() {
+    assert_eq!(fnv1a_hash_bit_reversed_64(b"\x01"), fnv1a_hash_64_case_sensitive(b"\x80", None));
+    assert_eq!(fnv1a_hash_bit_reversed_64(b"\xFF"), fnv1a_hash_64_case_sensitive(b"\xFF", None));
+    assert_eq!(fnv1a_hash_bit_reversed_64(b"\x0F\x03"), fnv1a_hash_64_case_sensitive(b"\xF0\xC0", None));
+    assert_eq!(fnv1a_hash_bit_reversed_64(b"\x81\x3C"), fnv1a_hash_64_case_sensitive(b"\x81\x3C", None));
+    assert_eq!(fnv1a_hash_bit_reversed_64(b""), FNV_OFFSET_BASIS_64);
+}
+
+#[cfg(feature = "unicode")]
+#[test]
+fn fnv1a_test_str_graphemes() {
+    assert_eq!(fnv1a_hash_str_64_graphemes("ab"), fnv1a_hash_str_64("a\u{1f}b"));
+    assert_eq!(fnv1a_hash_str_64_graphemes("e\u{301}"), fnv1a_hash_str_64("e\u{301}"));
+    assert_eq!(fnv1a_hash_str_64_graphemes("e\u{301}x"), fnv1a_hash_str_64("e\u{301}\u{1f}x"));
+    assert_ne!(fnv1a_hash_str_64_graphemes("e\u{301}"), fnv1a_hash_str_64_graphemes("\u{e9}"));
+    assert_eq!(fnv1a_hash_str_64_graphemes(""), FNV_OFFSET_BASIS_64);
+}
+
+#[test]
+fn fnv1a_test_repeated() {
+    const REPEATED: u64 = fnv1a_hash_64_repeated(b"ab", 3);
+    assert_eq!(REPEATED, fnv1a_hash_str_64("ababab"));
+    assert_eq!(fnv1a_hash_64_repeated(b"ab", 1), fnv1a_hash_str_64("ab"));
+    assert_eq!(fnv1a_hash_64_repeated(b"ab", 0), FNV_OFFSET_BASIS_64);
+    assert_eq!(fnv1a_hash_64_repeated(b"", 5), FNV_OFFSET_BASIS_64);
+}
+
+#[test]
+fn fnv1a_test_const_map() {
+    const_map!(TABLE: FnvMap<&str, u32, 16> = {
+        "alpha" => 1,
+        "beta" => 2,
+        "gamma" => 3,
+        "delta" => 4,
+        "epsilon" => 5,
+        "zeta" => 6,
+        "eta" => 7,
+        "theta" => 8,
+    });
+
+    let keys = ["alpha", "beta", "gamma", "delta", "epsilon", "zeta", "eta", "theta"];
+    for (value, key) in (1..).zip(keys.iter()) {
+        assert_eq!(TABLE.get(key), Some(&value));
+    }
+    assert_eq!(TABLE.get("iota"), None);
+    assert_eq!(TABLE.get(""), None);
+
+    const EMPTY: FnvMap<&str, u32, 0> = FnvMap::new([]);
+    assert_eq!(EMPTY.get("alpha"), None);
+}
+
+#[test]
+#[should_panic]
+fn fnv1a_test_const_map_full() {
+    let _ = FnvMap::<&str, u32, 1>::new([("alpha", 1), ("beta", 2)]);
+}
+
+#[test]
+fn fnv1a_test_gear() {
+    const TABLE: [u64; 256] = fnv1a_gear_build_table();
+    assert_eq!(TABLE, FNV1A_BYTE_TABLE_64);
+    assert_eq!(TABLE, fnv1a_gear_build_table());
+    for (i, entry) in TABLE.iter().enumerate() {
+        assert!(TABLE[i + 1..].iter().all(|other| other != entry));
+    }
+
+    let mut state = 0;
+    for &byte in b"content defined chunking" {
+        let next = fnv1a_gear_roll(state, byte, &TABLE);
+        assert_ne!(next, state);
+        assert_eq!(next, (state << 1) ^ TABLE[byte as usize]);
+        state = next;
+    }
+}
+
+#[test]
+fn fnv1a_test_length_check() {
+    assert_eq!(fnv1a_hash_length_64(5), fnv1a_hash_64_case_sensitive(&5usize.to_le_bytes(), None));
+    assert_ne!(fnv1a_hash_length_64(5), fnv1a_hash_length_64(6));
+
+    let hash = fnv1a_hash_str_64("hello");
+    assert!(fnv1a_hash_64_with_length_check(b"hello", hash, 5));
+    assert!(!fnv1a_hash_64_with_length_check(b"hellp", hash, 5));
+    assert!(!fnv1a_hash_64_with_length_check(b"hello", hash, 4));
+    assert!(!fnv1a_hash_64_with_length_check(b"hello", 0, 4));
+}
+
+#[test]
+fn fnv1a_test_poly_eval() {
+    let expected = (b'a' as u64 + b'b' as u64 * 256 + b'c' as u64 * 65536) % u32::MAX as u64;
+    assert_eq!(fnv1a_poly_eval_32(b"abc", 256, u32::MAX) as u64, expected);
+    assert_eq!(fnv1a_poly_eval_32(b"", 256, u32::MAX), 0);
+    assert_eq!(fnv1a_poly_eval_32(b"abc", 256, 1), 0);
+    assert_eq!(fnv1a_poly_eval_32(b"abc", 0, 1), 0);
+    assert_eq!(fnv1a_poly_eval_32(&[1, 1], 0, u32::MAX), FNV_OFFSET_BASIS_32 + 1);
+    assert_eq!(fnv1a_poly_eval_32(&[3, 2, 1], 10, 101), 123 % 101);
+}
+
+#[test]
+fn fnv1a_test_rotated() {
+    const SHORT: u64 = fnv1a_hash_64_rotated(b"abcdefg");
+    assert_eq!(SHORT, 0x406e475017aa7737);
+    assert_eq!(SHORT, fnv1a_hash_str_64("abcdefg"));
+    assert_eq!(fnv1a_hash_64_rotated(b"abcdefgh"), 0xb3692ed460c1b546);
+    assert_eq!(fnv1a_hash_64_rotated(b"abcdefgh"), fnv1a_hash_str_64("abcdefgh").rotate_right(13));
+    assert_eq!(fnv1a_hash_64_rotated(b"abcdefghi"), 0x9d6fc1e06926dedd);
+    assert_ne!(fnv1a_hash_64_rotated(b"abcdefghi"), fnv1a_hash_str_64("abcdefghi"));
+    assert_eq!(fnv1a_hash_64_rotated(b"0123456789abcdef"), 0xb9a23cc389cb91e5);
+}
+
+#[cfg(feature = "unicode")]
+#[test]
+fn fnv1a_test_str_emoji_count() {
+    assert_eq!(fnv1a_hash_str_64_with_emoji_count("hello"), (fnv1a_hash_str_64("hello"), 0));
+    assert_eq!(fnv1a_hash_str_64_with_emoji_count("hi \u{1f600}"), (fnv1a_hash_str_64("hi \u{1f600}"), 1));
+    assert_eq!(fnv1a_hash_str_64_with_emoji_count("\u{2764}\u{e9}\u{1f680}\u{1faff}\u{1fb00}").1, 3);
+    assert_eq!(fnv1a_hash_str_64_with_emoji_count(""), (FNV_OFFSET_BASIS_64, 0));
+}
+
+#[test]
+fn fnv1a_test_str_prefix_hashes() {
+    const HASHES: [u64; 6] = fnv1a_str_prefix_hashes_64("prefix");
+    assert_eq!(HASHES[5], fnv1a_hash_str_64("prefix"));
+    assert_eq!(HASHES[0], fnv1a_hash_64_case_sensitive(b"p", None));
+    assert_eq!(HASHES[2], fnv1a_hash_str_64("pre"));
+    for (i, hash) in HASHES.iter().enumerate() {
+        assert!(HASHES[i + 1..].iter().all(|other| other != hash));
+    }
+    assert_eq!(fnv1a_str_prefix_hashes_64::<0>(""), []);
+}
+
+#[test]
+#[should_panic]
+fn fnv1a_test_str_prefix_hashes_length_mismatch() {
+    let _ = fnv1a_str_prefix_hashes_64::<3>("prefix");
+}
+
+#[test]
+fn fnv1a_test_counted() {
+    assert_eq!(fnv1a_hash_32_counted(b"hello", Some(3)), (fnv1a_hash_str_32("hel"), 3));
+    assert_eq!(fnv1a_hash_32_counted(b"hello", None), (fnv1a_hash_str_32("hello"), 5));
+    assert_eq!(fnv1a_hash_32_counted(b"hello", Some(0)), (FNV_OFFSET_BASIS_32, 0));
+    assert_eq!(fnv1a_hash_32_counted(b"hello", Some(9)), (fnv1a_hash_str_32("hello"), 5));
+    assert_eq!(fnv1a_hash_32_counted(b"", Some(0)), (FNV_OFFSET_BASIS_32, 0));
+}
+
+#[test]
+fn fnv1a_test_xor_preprocessed() {
+    assert_eq!(fnv1a_hash_64_xor_preprocessed(b"ABC", 0x20), fnv1a_hash_str_64("abc"));
+    assert_eq!(fnv1a_hash_64_xor_preprocessed(b"\xFF", 0xFF), fnv1a_hash_64_case_sensitive(b"\x00", None));
+    assert_eq!(fnv1a_hash_64_xor_preprocessed(b"hello", 0), fnv1a_hash_str_64("hello"));
+}
+
+#[test]
+fn fnv1a_test_base64() {
+    assert_eq!(fnv1a_hash_base64_64("aGVsbG8="), Ok(fnv1a_hash_str_64("hello")));
+    assert_eq!(fnv1a_hash_base64_64("aGVsbG8sIHdvcmxk"), Ok(fnv1a_hash_str_64("hello, world")));
+    assert_eq!(fnv1a_hash_base64_64("aGk="), Ok(fnv1a_hash_str_64("hi")));
+    assert_eq!(fnv1a_hash_base64_64("aA=="), Ok(fnv1a_hash_str_64("h")));
+    assert_eq!(fnv1a_hash_base64_64("+/+/"), Ok(fnv1a_hash_64_case_sensitive(&[0xfb, 0xff, 0xbf], None)));
+    assert_eq!(fnv1a_hash_base64_64(""), Ok(FNV_OFFSET_BASIS_64));
+
+    assert_eq!(fnv1a_hash_base64_64("aGVs*G8="), Err(Base64Error::InvalidChar(b'*')));
+    assert_eq!(fnv1a_hash_base64_64("aGVsbG8"), Err(Base64Error::InvalidLength));
+    assert_eq!(fnv1a_hash_base64_64("aG=sbG8="), Err(Base64Error::InvalidPadding));
+    assert_eq!(fnv1a_hash_base64_64("aGVsbG=8"), Err(Base64Error::InvalidPadding));
+    assert_eq!(fnv1a_hash_base64_64("a==="), Err(Base64Error::InvalidPadding));
+    assert_eq!(fnv1a_hash_base64_64("aB=="), Err(Base64Error::InvalidPadding));
+    assert_eq!(fnv1a_hash_base64_64("aGl="), Err(Base64Error::InvalidPadding));
+    assert_eq!(fnv1a_hash_base64_64("aGVsbG9="), Err(Base64Error::InvalidPadding));
+}
+
+#[test]
+fn fnv1a_test_batch4() {
+    let inputs: [&[u8]; 6] = [b"", b"a", b"hello", b"hello world", b"\x00\xff", b"a somewhat longer input"];
+    for a in inputs {
+        for b in inputs {
+            let batch = [a, b, inputs[5], a];
+            let expected = batch.map(|input| fnv1a_hash_64_case_sensitive(input, None));
+            assert_eq!(fnv1a_hash_64_batch4(batch), expected);
+        }
+    }
+}
+
+#[test]
+fn fnv1a_test_matrix_rows() {
+    let matrix = *b"abcdefghijkl";
+    let mut out = [0; 4];
+    assert_eq!(fnv1a_hash_matrix_rows_32_into(&matrix, 4, &mut out), 3);
+    for i in 0..3 {
+        assert_eq!(out[i], fnv1a_hash_32_case_sensitive(&matrix[i * 4..(i + 1) * 4], None));
+    }
+    assert_eq!(out[3], 0);
+
+    assert_eq!(fnv1a_hash_matrix_rows_32_into(&matrix, 5, &mut out), 3);
+    assert_eq!(out[2], fnv1a_hash_str_32("kl"));
+    assert_eq!(fnv1a_hash_matrix_rows_32_into(&[], 5, &mut []), 0);
+}
+
+#[test]
+#[should_panic]
+fn fnv1a_test_matrix_rows_zero_cols() {
+    fnv1a_hash_matrix_rows_32_into(b"abc", 0, &mut [0; 4]);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn fnv1a_test_matrix_rows_vec() {
+    let matrix = *b"abcdefghijkl";
+    let hashes = fnv1a_hash_matrix_rows_32(&matrix, 4);
+    assert_eq!(hashes.len(), 3);
+    assert_eq!(hashes[1], fnv1a_hash_str_32("efgh"));
+}
@@ -0,0 +1,31 @@
+#![cfg(feature = "std")]
+
+use const_fnv1a_hash::{FnvHashMap, FnvHashSet};
+
+#[test]
+fn fnv_hash_map_roundtrip() {
+    let mut map: FnvHashMap<u32, String> = FnvHashMap::default();
+    for i in 0..1000 {
+        map.insert(i, format!("value-{}", i));
+    }
+    assert_eq!(map.len(), 1000);
+    for i in 0..1000 {
+        assert_eq!(map.get(&i).map(String::as_str), Some(format!("value-{}", i).as_str()));
+    }
+
+    let cloned = map.clone();
+    assert_eq!(cloned, map);
+    assert_eq!(cloned.keys().sum::<u32>(), (0..1000).sum());
+}
+
+#[test]
+fn fnv_hash_set_roundtrip() {
+    let set: FnvHashSet<String> = (0..1000).map(|i| format!("key-{}", i)).collect();
+    assert_eq!(set.len(), 1000);
+    assert!((0..1000).all(|i| set.contains(&format!("key-{}", i))));
+    assert!(!set.contains("missing"));
+
+    let cloned = set.clone();
+    assert_eq!(cloned, set);
+    assert!(cloned.iter().all(|key| key.starts_with("key-")));
+}
@@ -0,0 +1,27 @@
+#![cfg(feature = "hash")]
+
+use const_fnv1a_hash::{fnv1a_hash_str_64, FnvBuildHasher, FnvHasher};
+use std::collections::HashMap;
+use std::hash::Hasher;
+
+#[test]
+fn fnv_hasher_concatenates_writes() {
+    let mut hasher = FnvHasher::default();
+    hasher.write(b"hello ");
+    hasher.write(b"world");
+    assert_eq!(hasher.finish(), fnv1a_hash_str_64("hello world"));
+}
+
+#[test]
+fn fnv_build_hasher_in_hash_map() {
+    let keys: Vec<String> = (0..1000).map(|i| format!("key-{}", i)).collect();
+    let mut map: HashMap<&str, u32, FnvBuildHasher> = HashMap::default();
+    for (i, key) in keys.iter().enumerate() {
+        map.insert(key.as_str(), i as u32);
+    }
+    assert_eq!(map.len(), 1000);
+    for (i, key) in keys.iter().enumerate() {
+        assert_eq!(map.get(key.as_str()), Some(&(i as u32)));
+    }
+    assert_eq!(map.get("missing"), None);
+}